@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+
+use provenance::{self, Provenance};
+
+/// Error returned by the `build()` method of builders generated by `data_struct!`.
+#[derive(Debug)]
+pub enum BuildError {
+    /// An assertion in the `assertions: { ... }` block failed.
+    ///
+    /// The message is in the form `"assertion failed: '<assertion>'"`.
+    AssertionFailed(&'static str),
+    /// The default value expression of a field returned an error.
+    DefaultFailed {
+        /// Name of the field whose default value could not be evaluated.
+        field: &'static str,
+        /// Error returned by the default value expression.
+        source: Box<dyn Error + Send + Sync>,
+    },
+    /// The struct of a `#[builder(nested = ..)]` field could not be built.
+    Nested {
+        /// Name of the nested field.
+        field: &'static str,
+        /// Error returned when building the nested struct.
+        source: Box<BuildError>,
+    },
+    /// The fields of a field group, such as `#[builder(exactly_one(a, b))]`, were not set in
+    /// an allowed combination.
+    FieldGroup {
+        /// Rule of the field group that was broken.
+        kind: FieldGroupKind,
+        /// Names of the fields in the group.
+        fields: &'static [&'static str],
+    },
+    /// A field was not set although another field requires it, such as with
+    /// `#[builder(required_if(tls_cert, use_tls))]`.
+    FieldRequired {
+        /// Name of the field that was not set.
+        field: &'static str,
+        /// Name of the field that requires it.
+        required_by: &'static str,
+        /// Whether the field is required when `required_by` is true, or when it is set.
+        when: RequiredWhen,
+    },
+    /// A `#[builder(set_once)]` field was set more than once.
+    FieldOverwritten {
+        /// Name of the field that was set again.
+        field: &'static str,
+    },
+    /// The struct of a builder with `#[builder(provenance)]` could not be built.
+    WithProvenance {
+        /// Error returned when building the struct.
+        error: Box<BuildError>,
+        /// Name of each field, with where its value came from.
+        fields: Vec<(&'static str, Provenance)>,
+    },
+}
+
+/// Rule of a field group, which restricts how many of its fields may be set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldGroupKind {
+    /// `#[builder(exactly_one(..))]`: exactly one of the fields must be set.
+    ExactlyOne,
+    /// `#[builder(at_most_one(..))]`: no more than one of the fields may be set.
+    AtMostOne,
+    /// `#[builder(at_least_one(..))]`: one or more of the fields must be set.
+    AtLeastOne,
+    /// `#[builder(all_or_none(..))]`: either all of the fields or none of them must be set.
+    AllOrNone,
+}
+
+/// Condition under which a field is required by another field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequiredWhen {
+    /// `#[builder(required_if(..))]`: the other field is `true`.
+    True,
+    /// `#[builder(required_with(..))]`: the other field is set.
+    Set,
+}
+
+impl fmt::Display for FieldGroupKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rule = match *self {
+            FieldGroupKind::ExactlyOne => "exactly one",
+            FieldGroupKind::AtMostOne => "at most one",
+            FieldGroupKind::AtLeastOne => "at least one",
+            FieldGroupKind::AllOrNone => "all or none",
+        };
+        write!(f, "{}", rule)
+    }
+}
+
+impl BuildError {
+    /// Returns the path to the field that caused the error, e.g. `"server.port"`.
+    ///
+    /// Returns `None` if the error was not caused by a particular field, such as a failed
+    /// assertion of the outermost struct. A failed assertion of a nested struct returns the path
+    /// to the nested field, e.g. `"server"`.
+    pub fn field_path(&self) -> Option<String> {
+        match *self {
+            BuildError::AssertionFailed(_) | BuildError::FieldGroup { .. } => None,
+            BuildError::DefaultFailed { field, .. } |
+            BuildError::FieldRequired { field, .. } |
+            BuildError::FieldOverwritten { field } => Some(field.to_string()),
+            BuildError::WithProvenance { ref error, .. } => error.field_path(),
+            BuildError::Nested { field, ref source } => {
+                match source.field_path() {
+                    Some(path) => Some(format!("{}.{}", field, path)),
+                    None => Some(field.to_string()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::AssertionFailed(message) => write!(f, "{}", message),
+            BuildError::DefaultFailed { field, ref source } => {
+                write!(f, "failed to evaluate default for field: '{}': {}", field, source)
+            }
+            BuildError::Nested { field, ref source } => {
+                write!(f, "failed to build field: '{}': {}", field, source)
+            }
+            BuildError::FieldGroup { kind, fields } => {
+                let fields = fields
+                    .iter()
+                    .map(|field| format!("'{}'", field))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{} of the fields {} must be set", kind, fields)
+            }
+            BuildError::FieldRequired { field, required_by, when } => {
+                let condition = match when {
+                    RequiredWhen::True => "true",
+                    RequiredWhen::Set => "set",
+                };
+                write!(f, "field '{}' must be set when '{}' is {}", field, required_by, condition)
+            }
+            BuildError::FieldOverwritten { field } => {
+                write!(f, "field '{}' may only be set once", field)
+            }
+            BuildError::WithProvenance { ref error, ref fields } => {
+                write!(f, "{} ({})", error, provenance::describe(fields))
+            }
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BuildError::AssertionFailed(_) |
+            BuildError::FieldGroup { .. } |
+            BuildError::FieldRequired { .. } |
+            BuildError::FieldOverwritten { .. } => None,
+            BuildError::DefaultFailed { ref source, .. } => Some(&**source),
+            BuildError::Nested { ref source, .. } => Some(&**source),
+            BuildError::WithProvenance { ref error, .. } => Some(&**error),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// Trait implemented by every generated builder.
+///
+/// This allows code to be generic over builders, regardless of whether they were generated by
+/// `data_struct!` or `object_struct!`, or are consuming or non-consuming.
+///
+/// The method is named `try_build` instead of `build` so that it does not shadow the generated
+/// `build()` method when this trait is in scope.
+pub trait Builder {
+    /// The struct that is built.
+    type Output;
+    /// The error returned when building fails.
+    ///
+    /// This is `BuildError` for `data_struct!` builders, and `Infallible` for `object_struct!`
+    /// builders as they panic instead.
+    type Error;
+
+    /// Builds the struct.
+    fn try_build(self) -> Result<Self::Output, Self::Error>;
+}
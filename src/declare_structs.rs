@@ -1,15 +1,129 @@
 #[macro_export]
 /// Declares the type struct and its corresponding builder struct.
 macro_rules! declare_structs {
+    // Nested fields store the builder of the field's struct instead of the struct
+    (
+        @builder_field_type
+        nested: [],
+        ty: $F_TY:ty
+    ) => {
+        $F_TY
+    };
+    (
+        @builder_field_type
+        nested: [ $NESTED:ident ],
+        ty: $F_TY:ty
+    ) => {
+        $NESTED
+    };
+
+    // Structs with a patch have a companion struct where each field with a setter is optional
+    (
+        @patch
+        patch: false,
+        patch_meta: $PATCH_META:tt,
+        vis: $VIS:tt,
+        spec: $STRUCT:ident,
+        fields: $FIELDS:tt
+    ) => ();
+    // Fields without a setter can't be changed, so they are left out of the patch
+    (
+        @patch
+        patch: true,
+        patch_meta: $PATCH_META:tt,
+        vis: $VIS:tt,
+        spec: $STRUCT:ident,
+        fields: [ { no_setter: true, spec: $F_NAME:ident: $F_TY:ty } $( $FIELDS:tt )* ],
+        patch_fields: [ $( $PATCH_FIELDS:tt )* ]
+    ) => {
+        declare_structs!(
+            @patch
+            patch: true,
+            patch_meta: $PATCH_META,
+            vis: $VIS,
+            spec: $STRUCT,
+            fields: [ $( $FIELDS )* ],
+            patch_fields: [ $( $PATCH_FIELDS )* ]
+        );
+    };
+    (
+        @patch
+        patch: true,
+        patch_meta: $PATCH_META:tt,
+        vis: $VIS:tt,
+        spec: $STRUCT:ident,
+        fields: [ { no_setter: false, spec: $F_NAME:ident: $F_TY:ty } $( $FIELDS:tt )* ],
+        patch_fields: [ $( $PATCH_FIELDS:tt )* ]
+    ) => {
+        declare_structs!(
+            @patch
+            patch: true,
+            patch_meta: $PATCH_META,
+            vis: $VIS,
+            spec: $STRUCT,
+            fields: [ $( $FIELDS )* ],
+            patch_fields: [ $( $PATCH_FIELDS )* { $F_NAME: $F_TY } ]
+        );
+    };
+    (
+        @patch
+        patch: true,
+        patch_meta: [ $( #[$PATCH_META:meta] )* ],
+        vis: [ $( $VIS:ident )* ],
+        spec: $STRUCT:ident,
+        fields: [],
+        patch_fields: [ $( { $F_NAME:ident: $F_TY:ty } )* ]
+    ) => {
+        $crate::paste::paste! {
+            #[doc = concat!("Changes to a `", stringify!($STRUCT), "`, applied with `apply()`.")]
+            #[derive(Default)]
+            $( #[$PATCH_META] )*
+            $( $VIS )* struct [<$STRUCT Patch>] {
+                $(
+                    #[doc = concat!(
+                        "New value of `", stringify!($F_NAME), "`, or `None` to leave it unchanged."
+                    )]
+                    pub $F_NAME: Option<$F_TY>,
+                )*
+            }
+        }
+    };
+    (
+        @patch
+        patch: true,
+        patch_meta: $PATCH_META:tt,
+        vis: $VIS:tt,
+        spec: $STRUCT:ident,
+        fields: $FIELDS:tt
+    ) => {
+        declare_structs!(
+            @patch
+            patch: true,
+            patch_meta: $PATCH_META,
+            vis: $VIS,
+            spec: $STRUCT,
+            fields: $FIELDS,
+            patch_fields: []
+        );
+    };
+
     (
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$META:meta] )* ],
+        builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+        sources: [ $( $SOURCES:ident )* ],
+        patch: $PATCH:ident,
+        patch_meta: $PATCH_META:tt,
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
         fields: {
             $(
                 {
                     vis: [ $( $FIELD_VIS:ident )* ],
                     meta: [ $( #[$F_META:meta] )* ],
+                    struct_meta: [ $( #[$F_STRUCT_META:meta] )* ],
+                    builder_meta: [ $( #[$F_BUILDER_META:meta] )* ],
+                    no_setter: $F_NO_SETTER:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
                     spec: $F_NAME:ident: $F_TY:ty
                 } $(,)*
             )*
@@ -21,20 +135,34 @@ macro_rules! declare_structs {
         $( $VIS )* struct $STRUCT {
             $(
                 $( #[$F_META] )*
+                $( #[$F_STRUCT_META] )*
                 $( $FIELD_VIS )* $F_NAME : $F_TY,
             )*
         }
 
-        // Unfortunately we cannot make the docs specific to the struct
-        // e.g. passing stringify!($STRUCT)
-        // See https://github.com/rust-lang/rust/issues/12404#issuecomment-35557322
-        /// Auto-generated builder
+        #[doc = concat!("Builder for `", stringify!($STRUCT), "`.")]
+        $( #[$BUILDER_META] )*
         $( $VIS )* struct $BUILDER {
             // builder fields shouldn't have to be visible
             $(
                 $( #[$F_META] )*
-                $F_NAME : Option<$F_TY>,
+                $( #[$F_BUILDER_META] )*
+                $F_NAME : $crate::FieldValue<declare_structs!(
+                    @builder_field_type
+                    nested: [ $( $F_NESTED )* ],
+                    ty: $F_TY
+                )>,
             )*
+            $( $SOURCES: $crate::Sources, )*
         }
+
+        declare_structs!(
+            @patch
+            patch: $PATCH,
+            patch_meta: $PATCH_META,
+            vis: [ $( $VIS )* ],
+            spec: $STRUCT,
+            fields: [ $( { no_setter: $F_NO_SETTER, spec: $F_NAME: $F_TY } )* ]
+        );
     };
 }
@@ -0,0 +1,50 @@
+#[macro_export]
+/// Declares a group of fields, and optionally assertions, that can be included in the fields of
+/// `data_struct!` and `object_struct!` with `..group_name,`.
+/// See [the module documentation](index.html) for more.
+///
+/// This declares a macro named after the group, so the group must be declared before the structs
+/// that include it.
+macro_rules! field_group {
+    (
+        $( #[$GROUP_META:meta] )*
+        $GROUP:ident {
+            $( $FIELD_SPEC:tt )*
+        }
+        $(, assertions: $ASSERTIONS:tt )*
+    )
+    =>
+    {
+        field_group! {
+            @dollar ($)
+            meta: [ $( #[$GROUP_META] )* ],
+            group: $GROUP,
+            fields: { $( $FIELD_SPEC )* },
+            assertions: [ $( $ASSERTIONS )* ]
+        }
+    };
+
+    // `$` cannot be written in the macro that is declared, so it is passed in as a token.
+    //
+    // The declared macro calls back into `parse_struct!` with the fields and assertions of the
+    // group, followed by the state of the struct that includes the group.
+    (
+        @dollar ($DOLLAR:tt)
+        meta: [ $( #[$GROUP_META:meta] )* ],
+        group: $GROUP:ident,
+        fields: { $( $FIELD_SPEC:tt )* },
+        assertions: [ $( $ASSERTIONS:tt )* ]
+    )
+    =>
+    {
+        $( #[$GROUP_META] )*
+        macro_rules! $GROUP {
+            ( $DOLLAR CALLBACK:ident! { $DOLLAR ( $DOLLAR STATE:tt )* } ) => {
+                $DOLLAR CALLBACK! {
+                    @field_group { $( $FIELD_SPEC )* } [ $( $ASSERTIONS )* ]
+                    $DOLLAR ( $DOLLAR STATE )*
+                }
+            };
+        }
+    };
+}
@@ -0,0 +1,104 @@
+use std::mem;
+
+/// Value of a field in a generated builder.
+///
+/// This tracks whether a field has been explicitly set, or is using its default value.
+///
+/// This is an implementation detail of the generated builders, and is only public so that the
+/// macros can refer to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FieldValue<T> {
+    /// The field uses a lazy default that has not been evaluated yet.
+    Unset,
+    /// The field uses its default value.
+    Default(T),
+    /// The field has been set through the constructor or a setter.
+    Set(T),
+    /// The field has the value of a built struct that was converted back into a builder, such as
+    /// with `to_builder()`.
+    ///
+    /// This does not count as set, as the struct was already checked when it was built.
+    Built(T),
+    /// The `#[builder(set_once)]` field has been set more than once, which fails the build.
+    ///
+    /// This holds the value that was set last.
+    Overwritten(T),
+}
+
+impl<T> FieldValue<T> {
+    /// Returns a reference to the value, or `None` if the field is `Unset`.
+    pub fn value(&self) -> Option<&T> {
+        match *self {
+            FieldValue::Unset => None,
+            FieldValue::Default(ref value) |
+            FieldValue::Set(ref value) |
+            FieldValue::Built(ref value) |
+            FieldValue::Overwritten(ref value) => Some(value),
+        }
+    }
+
+    /// Returns the value, or `None` if the field is `Unset`.
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            FieldValue::Unset => None,
+            FieldValue::Default(value) |
+            FieldValue::Set(value) |
+            FieldValue::Built(value) |
+            FieldValue::Overwritten(value) => Some(value),
+        }
+    }
+
+    /// Marks the field as explicitly set, and returns a mutable reference to the value.
+    ///
+    /// Returns `None` if the field is `Unset`.
+    pub fn set_mut(&mut self) -> Option<&mut T> {
+        *self = match mem::replace(self, FieldValue::Unset) {
+            FieldValue::Unset => FieldValue::Unset,
+            FieldValue::Default(value) |
+            FieldValue::Set(value) |
+            FieldValue::Built(value) => FieldValue::Set(value),
+            FieldValue::Overwritten(value) => FieldValue::Overwritten(value),
+        };
+        match *self {
+            FieldValue::Set(ref mut value) |
+            FieldValue::Overwritten(ref mut value) => Some(value),
+            FieldValue::Unset |
+            FieldValue::Default(_) |
+            FieldValue::Built(_) => None,
+        }
+    }
+
+    /// Returns whether the field has been explicitly set.
+    pub fn is_set(&self) -> bool {
+        match *self {
+            FieldValue::Set(_) |
+            FieldValue::Overwritten(_) => true,
+            FieldValue::Unset |
+            FieldValue::Default(_) |
+            FieldValue::Built(_) => false,
+        }
+    }
+
+    /// Returns whether the field has the value of a built struct that was converted back into a
+    /// builder.
+    pub fn is_built(&self) -> bool {
+        match *self {
+            FieldValue::Built(_) => true,
+            FieldValue::Unset |
+            FieldValue::Default(_) |
+            FieldValue::Set(_) |
+            FieldValue::Overwritten(_) => false,
+        }
+    }
+
+    /// Returns whether the `#[builder(set_once)]` field has been set more than once.
+    pub fn is_overwritten(&self) -> bool {
+        match *self {
+            FieldValue::Overwritten(_) => true,
+            FieldValue::Unset |
+            FieldValue::Default(_) |
+            FieldValue::Set(_) |
+            FieldValue::Built(_) => false,
+        }
+    }
+}
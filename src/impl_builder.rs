@@ -1,9 +1,11 @@
 #[doc(hidden)]
 #[macro_export]
-/// Implements the setters and build method for the consuming variant of the builder.
+/// Implements the constructor, setters and build method of the builder.
 macro_rules! impl_builder {
     (
         @constructor
+        constructor: $CONSTRUCTOR:ident,
+        sources: [ $( $SOURCES:ident )* ],
         spec: $BUILDER:ident -> $STRUCT:ident,
         fields: {
             $( $FIELDS_SPEC:tt )*
@@ -13,10 +15,13 @@ macro_rules! impl_builder {
     {
         impl_builder!(
             @constructor
+            constructor: $CONSTRUCTOR,
             spec: $BUILDER -> $STRUCT,
             separator: [],
             params: [],
-            assignments: [],
+            params_docs_header: [],
+            params_docs: [],
+            assignments: [ $( { $SOURCES: ::std::default::Default::default(), }, )* ],
             fields: {
                 $( $FIELDS_SPEC )*
             }
@@ -31,13 +36,19 @@ macro_rules! impl_builder {
     // Must not skip over any parameters in the assignment.
     (
         @constructor
+        constructor: $CONSTRUCTOR:ident,
         spec: $BUILDER:ident -> $STRUCT:ident,
         separator: [ $( $SEPARATOR:tt )* ],
         params: [ $( { $( $PARAMS:tt )* }, )* ],
+        params_docs_header: [ $( $PARAMS_DOCS_HEADER:tt )* ],
+        params_docs: [ $( { $( $PARAMS_DOCS:tt )* }, )* ],
         assignments: [ $( { $( $ASSIGNMENTS:tt )* }, )* ],
         fields: {
             {
                 req: false,
+                lazy: false,
+                docs: [ $( #[doc = $F_DOC:expr] )* ],
+                nested: [ $( $F_NESTED:ident )* ],
                 default: $FIELD_DEFAULT:expr,
                 spec: $F_NAME:ident: $F_TY:ty
             },
@@ -48,24 +59,82 @@ macro_rules! impl_builder {
     {
         impl_builder!(
             @constructor
+            constructor: $CONSTRUCTOR,
             spec: $BUILDER -> $STRUCT,
             separator: [ $( $SEPARATOR )* ],
             params: [ $( { $( $PARAMS )* }, )* ],
-            assignments: [ $( { $( $ASSIGNMENTS )* }, )* { $F_NAME: Some($FIELD_DEFAULT), }, ],
+            params_docs_header: [ $( $PARAMS_DOCS_HEADER )* ],
+            params_docs: [ $( { $( $PARAMS_DOCS )* }, )* ],
+            assignments: [
+                $( { $( $ASSIGNMENTS )* }, )*
+                {
+                    $F_NAME: $crate::FieldValue::Default(impl_builder!(
+                        @builder_value
+                        nested: [ $( $F_NESTED )* ],
+                        value: $FIELD_DEFAULT
+                    )),
+                },
+            ],
+            fields: {
+                $( $FIELDS_SPEC )*
+            }
+        );
+    };
+    // Lazy defaults are evaluated when building the struct, so the field starts off unset
+    (
+        @constructor
+        constructor: $CONSTRUCTOR:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        separator: [ $( $SEPARATOR:tt )* ],
+        params: [ $( { $( $PARAMS:tt )* }, )* ],
+        params_docs_header: [ $( $PARAMS_DOCS_HEADER:tt )* ],
+        params_docs: [ $( { $( $PARAMS_DOCS:tt )* }, )* ],
+        assignments: [ $( { $( $ASSIGNMENTS:tt )* }, )* ],
+        fields: {
+            {
+                req: false,
+                lazy: true,
+                docs: [ $( #[doc = $F_DOC:expr] )* ],
+                nested: [ $( $F_NESTED:ident )* ],
+                default: $FIELD_DEFAULT:expr,
+                spec: $F_NAME:ident: $F_TY:ty
+            },
+            $( $FIELDS_SPEC:tt )*
+        }
+    )
+    =>
+    {
+        impl_builder!(
+            @constructor
+            constructor: $CONSTRUCTOR,
+            spec: $BUILDER -> $STRUCT,
+            separator: [ $( $SEPARATOR )* ],
+            params: [ $( { $( $PARAMS )* }, )* ],
+            params_docs_header: [ $( $PARAMS_DOCS_HEADER )* ],
+            params_docs: [ $( { $( $PARAMS_DOCS )* }, )* ],
+            assignments: [ $( { $( $ASSIGNMENTS )* }, )* { $F_NAME: $crate::FieldValue::Unset, }, ],
             fields: {
                 $( $FIELDS_SPEC )*
             }
         );
     };
+    // Each parameter is documented with the docs of its field. The docs are indented so that they
+    // are rendered as part of the parameter's list item.
     (
         @constructor
+        constructor: $CONSTRUCTOR:ident,
         spec: $BUILDER:ident -> $STRUCT:ident,
         separator: [ $( $SEPARATOR:tt )* ],
         params: [ $( { $( $PARAMS:tt )* }, )* ],
+        params_docs_header: [ $( $PARAMS_DOCS_HEADER:tt )* ],
+        params_docs: [ $( { $( $PARAMS_DOCS:tt )* }, )* ],
         assignments: [ $( { $( $ASSIGNMENTS:tt )* }, )* ],
         fields: {
             {
                 req: true,
+                lazy: $FIELD_LAZY:ident,
+                docs: [ $( #[doc = $F_DOC:expr] )* ],
+                nested: [ $( $F_NESTED:ident )* ],
                 default: $FIELD_DEFAULT:expr,
                 spec: $F_NAME:ident: $F_TY:ty
             },
@@ -76,266 +145,2672 @@ macro_rules! impl_builder {
     {
         impl_builder!(
             @constructor
+            constructor: $CONSTRUCTOR,
             spec: $BUILDER -> $STRUCT,
             separator: [ , ],
             params: [ $( { $( $PARAMS )* }, )* { $( $SEPARATOR )* $F_NAME: $F_TY }, ],
-            assignments: [ $( { $( $ASSIGNMENTS )* }, )* { $F_NAME: Some($F_NAME), }, ],
+            params_docs_header: [ #[doc = ""] #[doc = "# Parameters"] #[doc = ""] ],
+            params_docs: [
+                $( { $( $PARAMS_DOCS )* }, )*
+                {
+                    #[doc = concat!("* `", stringify!($F_NAME), "`:")]
+                    $( #[doc = concat!("  ", $F_DOC)] )*
+                },
+            ],
+            assignments: [
+                $( { $( $ASSIGNMENTS )* }, )*
+                {
+                    $F_NAME: $crate::FieldValue::Set(impl_builder!(
+                        @builder_value
+                        nested: [ $( $F_NESTED )* ],
+                        value: $F_NAME
+                    )),
+                },
+            ],
             fields: {
                 $( $FIELDS_SPEC )*
             }
         );
     };
     (
-        @constructor
+        @constructor
+        constructor: $CONSTRUCTOR:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        separator: [ $( $SEPARATOR:tt )* ],
+        params: [ $( { $( $PARAMS:tt )* }, )* ],
+        params_docs_header: [ $( $PARAMS_DOCS_HEADER:tt )* ],
+        params_docs: [ $( { $( $PARAMS_DOCS:tt )* }, )* ],
+        assignments: [ $( { $( $ASSIGNMENTS:tt )* }, )* ],
+        fields: {}
+    )
+    =>
+    {
+        impl $BUILDER {
+            // allow dead code because the user may construct the builder using `builder()` instead
+            #[allow(dead_code)]
+            #[doc = concat!("Constructs a builder for `", stringify!($STRUCT), "`.")]
+            $( $PARAMS_DOCS_HEADER )*
+            $( $( $PARAMS_DOCS )* )*
+            pub fn $CONSTRUCTOR( $( $( $PARAMS )* )* ) -> $BUILDER {
+                $BUILDER {
+                    $( $( $ASSIGNMENTS )* )*
+                }
+            }
+        }
+
+        impl $STRUCT {
+            // allow dead code because the user may construct the builder using `new()` instead
+            #[allow(dead_code)]
+            #[doc = concat!("Returns a new `", stringify!($BUILDER), "` for this struct.")]
+            $( $PARAMS_DOCS_HEADER )*
+            $( $( $PARAMS_DOCS )* )*
+            pub fn builder( $( $( $PARAMS )* )* ) -> $BUILDER {
+                $BUILDER {
+                    $( $( $ASSIGNMENTS )* )*
+                }
+            }
+        }
+    };
+
+    // Generate setter for non-mandatory fields, documented with the docs of the field.
+    //
+    // Unless the field has a custom setter name, the setter name is the field name with the
+    // configured prefix and suffix.
+    (
+        @setter
+        variant: $VARIANT:ident,
+        no_setter: true,
+        $( $SETTER_SPEC:tt )*
+    ) => ();
+    (
+        @setter
+        variant: $VARIANT:ident,
+        no_setter: false,
+        setter: [],
+        setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+        setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+        req: $FIELD_REQ:ident,
+        lazy: $FIELD_LAZY:ident,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        nested: [ $( $F_NESTED:ident )* ],
+        set_once: $F_SET_ONCE:ident,
+        sources: $SOURCES:tt,
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        impl_builder!(
+            @setter
+            variant: $VARIANT,
+            setter: [ $( $SETTER_PREFIX )* $F_NAME $( $SETTER_SUFFIX )* ],
+            req: $FIELD_REQ,
+            lazy: $FIELD_LAZY,
+            docs: [ $( #[doc = $F_DOC] )* ],
+            nested: [ $( $F_NESTED )* ],
+            set_once: $F_SET_ONCE,
+            sources: $SOURCES,
+            default: $FIELD_DEFAULT,
+            spec: $F_NAME: $F_TY
+        );
+    };
+    (
+        @setter
+        variant: $VARIANT:ident,
+        no_setter: false,
+        setter: [ $( $SETTER:tt )+ ],
+        setter_prefix: $SETTER_PREFIX:tt,
+        setter_suffix: $SETTER_SUFFIX:tt,
+        req: $FIELD_REQ:ident,
+        lazy: $FIELD_LAZY:ident,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        nested: [ $( $F_NESTED:ident )* ],
+        set_once: $F_SET_ONCE:ident,
+        sources: $SOURCES:tt,
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        impl_builder!(
+            @setter
+            variant: $VARIANT,
+            setter: [ $( $SETTER )+ ],
+            req: $FIELD_REQ,
+            lazy: $FIELD_LAZY,
+            docs: [ $( #[doc = $F_DOC] )* ],
+            nested: [ $( $F_NESTED )* ],
+            set_once: $F_SET_ONCE,
+            sources: $SOURCES,
+            default: $FIELD_DEFAULT,
+            spec: $F_NAME: $F_TY
+        );
+    };
+    (
+        @setter
+        variant: non_consuming,
+        setter: [ $( $SETTER:tt )+ ],
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        nested: [ $( $F_NESTED:ident )* ],
+        set_once: $F_SET_ONCE:ident,
+        sources: [ $( $SOURCES:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may be using the field default
+            #[allow(dead_code)]
+            #[doc = concat!("Sets `", stringify!($F_NAME), "`.")]
+            #[doc = ""]
+            $( #[doc = $F_DOC] )*
+            pub fn [<$( $SETTER )+>](&mut self, value: $F_TY) -> &mut Self {
+                self.$F_NAME = impl_builder!(
+                    @set_value
+                    set_once: $F_SET_ONCE,
+                    current: self.$F_NAME,
+                    value: impl_builder!(@builder_value nested: [ $( $F_NESTED )* ], value: value)
+                );
+                $( self.$SOURCES.record(stringify!($F_NAME)); )*
+                self
+            }
+        }
+    };
+    (
+        @setter
+        variant: consuming,
+        setter: [ $( $SETTER:tt )+ ],
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        nested: [ $( $F_NESTED:ident )* ],
+        set_once: $F_SET_ONCE:ident,
+        sources: [ $( $SOURCES:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may be using the field default
+            #[allow(dead_code)]
+            #[doc = concat!("Sets `", stringify!($F_NAME), "`.")]
+            #[doc = ""]
+            $( #[doc = $F_DOC] )*
+            pub fn [<$( $SETTER )+>](mut self, value: $F_TY) -> Self {
+                self.$F_NAME = impl_builder!(
+                    @set_value
+                    set_once: $F_SET_ONCE,
+                    current: self.$F_NAME,
+                    value: impl_builder!(@builder_value nested: [ $( $F_NESTED )* ], value: value)
+                );
+                $( self.$SOURCES.record(stringify!($F_NAME)); )*
+                self
+            }
+        }
+    };
+    (
+        @setter
+        variant: $VARIANT:ident,
+        setter: [ $( $SETTER:tt )+ ],
+        req: true,
+        lazy: $FIELD_LAZY:ident,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        nested: [ $( $F_NESTED:ident )* ],
+        set_once: $F_SET_ONCE:ident,
+        sources: $SOURCES:tt,
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+
+    // Set-once fields that are set again are marked as overwritten, which fails the build
+    (
+        @set_value
+        set_once: false,
+        current: $CURRENT:expr,
+        value: $VALUE:expr
+    ) => {
+        $crate::FieldValue::Set($VALUE)
+    };
+    (
+        @set_value
+        set_once: true,
+        current: $CURRENT:expr,
+        value: $VALUE:expr
+    ) => {
+        if $CURRENT.is_set() {
+            $crate::FieldValue::Overwritten($VALUE)
+        } else {
+            $crate::FieldValue::Set($VALUE)
+        }
+    };
+
+    // Generate a method to change the builder of nested fields in place, which marks the field as
+    // set
+    (
+        @nested_mut
+        variant: $VARIANT:ident,
+        no_setter: true,
+        nested: [ $( $F_NESTED:ident )* ],
+        sources: $SOURCES:tt,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+    (
+        @nested_mut
+        variant: $VARIANT:ident,
+        no_setter: false,
+        nested: [],
+        sources: $SOURCES:tt,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+    (
+        @nested_mut
+        variant: non_consuming,
+        no_setter: false,
+        nested: [ $NESTED:ident ],
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may be using the setter or the field default
+            #[allow(dead_code)]
+            #[doc = concat!(
+                "Changes the `", stringify!($NESTED), "` of `", stringify!($F_NAME), "`."
+            )]
+            pub fn [<$F_NAME _mut>]<F>(&mut self, f: F) -> &mut Self
+                where F: FnOnce(&mut $NESTED)
+            {
+                if let Some(builder) = self.$F_NAME.set_mut() {
+                    f(builder);
+                }
+                $( self.$SOURCES.record(stringify!($F_NAME)); )*
+                self
+            }
+        }
+    };
+    (
+        @nested_mut
+        variant: consuming,
+        no_setter: false,
+        nested: [ $NESTED:ident ],
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may be using the setter or the field default
+            #[allow(dead_code)]
+            #[doc = concat!(
+                "Changes the `", stringify!($NESTED), "` of `", stringify!($F_NAME), "`."
+            )]
+            pub fn [<$F_NAME _mut>]<F>(mut self, f: F) -> Self
+                where F: FnOnce(&mut $NESTED)
+            {
+                if let Some(builder) = self.$F_NAME.set_mut() {
+                    f(builder);
+                }
+                $( self.$SOURCES.record(stringify!($F_NAME)); )*
+                self
+            }
+        }
+    };
+
+    // Generate accessors for every field, which may be used to inspect the builder before building
+    // the struct.
+    //
+    // Only non-mandatory fields may be unset, as mandatory fields do not have a default value.
+    (
+        @accessors
+        variant: $VARIANT:ident,
+        req: $FIELD_REQ:ident,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to inspect the builder
+            #[allow(dead_code)]
+            #[doc = concat!("Returns the value of `", stringify!($F_NAME), "`.")]
+            #[doc = ""]
+            #[doc = "Returns `None` if the field has a lazy default that has not been evaluated."]
+            pub fn [<get_ $F_NAME>](&self)
+                -> Option<&declare_structs!(
+                    @builder_field_type
+                    nested: [ $( $F_NESTED )* ],
+                    ty: $F_TY
+                )>
+            {
+                self.$F_NAME.value()
+            }
+
+            // allow dead code because the user may not need to inspect the builder
+            #[allow(dead_code)]
+            #[doc = concat!("Returns whether `", stringify!($F_NAME), "` has been explicitly set.")]
+            pub fn [<is_ $F_NAME _set>](&self) -> bool {
+                self.$F_NAME.is_set()
+            }
+        }
+
+        impl_builder!(
+            @unset
+            variant: $VARIANT,
+            req: $FIELD_REQ,
+            lazy: $FIELD_LAZY,
+            nested: [ $( $F_NESTED )* ],
+            default: $FIELD_DEFAULT,
+            spec: $F_NAME: $F_TY
+        );
+    };
+    (
+        @unset
+        variant: non_consuming,
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to unset the field
+            #[allow(dead_code)]
+            #[doc = concat!("Returns `", stringify!($F_NAME), "` to its default value.")]
+            pub fn [<unset_ $F_NAME>](&mut self) -> &mut Self {
+                self.$F_NAME = impl_builder!(
+                    @initial_value
+                    lazy: $FIELD_LAZY,
+                    nested: [ $( $F_NESTED )* ],
+                    default: $FIELD_DEFAULT
+                );
+                self
+            }
+        }
+    };
+    (
+        @unset
+        variant: consuming,
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to unset the field
+            #[allow(dead_code)]
+            #[doc = concat!("Returns `", stringify!($F_NAME), "` to its default value.")]
+            pub fn [<unset_ $F_NAME>](mut self) -> Self {
+                self.$F_NAME = impl_builder!(
+                    @initial_value
+                    lazy: $FIELD_LAZY,
+                    nested: [ $( $F_NESTED )* ],
+                    default: $FIELD_DEFAULT
+                );
+                self
+            }
+        }
+    };
+    (
+        @unset
+        variant: $VARIANT:ident,
+        req: true,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+
+    // Generate a function that checks whether the value of a converted struct is the default of
+    // the field, which field groups use to decide whether it counts as set.
+    //
+    // Mandatory fields do not have a default value, so their values always count as set.
+    (
+        @is_default
+        purpose: $PURPOSE:ident,
+        req: true,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the field may not be part of a field group
+            #[allow(dead_code)]
+            fn [<__ $F_NAME _is_default>]<V>(_value: &$crate::FieldValue<V>) -> bool {
+                false
+            }
+        }
+    };
+    (
+        @is_default
+        purpose: data,
+        req: false,
+        lazy: true,
+        nested: [],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the field may not be part of a field group, and bare trait
+            // objects because the struct already warns about the field type
+            #[allow(dead_code, bare_trait_objects)]
+            fn [<__ $F_NAME _is_default>]<V>(value: &$crate::FieldValue<V>) -> bool
+                where V: PartialEq<$F_TY>
+            {
+                let default = || -> Result<$F_TY, Box<dyn std::error::Error + Send + Sync>> {
+                    Ok($FIELD_DEFAULT)
+                };
+                match (value.value(), default()) {
+                    (Some(value), Ok(default)) => *value == default,
+                    _ => false,
+                }
+            }
+        }
+    };
+    (
+        @is_default
+        purpose: $PURPOSE:ident,
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the field may not be part of a field group, and bare trait
+            // objects because the struct already warns about the field type
+            #[allow(dead_code, bare_trait_objects)]
+            fn [<__ $F_NAME _is_default>]<V>(value: &$crate::FieldValue<V>) -> bool
+                where V: PartialEq<
+                    declare_structs!(@builder_field_type nested: [ $( $F_NESTED )* ], ty: $F_TY)
+                >
+            {
+                value.value().map_or(false, |value| {
+                    *value == impl_builder!(
+                        @builder_value
+                        nested: [ $( $F_NESTED )* ],
+                        value: $FIELD_DEFAULT
+                    )
+                })
+            }
+        }
+    };
+
+    // Collects the final names of the setters, with the configured prefix and suffix, and appends
+    // them to the callback as `setters: [ ... ]`. Mandatory fields and fields without a setter are
+    // left out, as they have no setter that could clash with a generated method.
+    (
+        @setter_names
+        setter_prefix: $SETTER_PREFIX:tt,
+        setter_suffix: $SETTER_SUFFIX:tt,
+        fields: [
+            { req: true, no_setter: $F_NO_SETTER:ident, setter: $F_SETTER:tt, name: $F_NAME:ident }
+            $( $FIELDS:tt )*
+        ],
+        names: [ $( $NAMES:tt )* ],
+        then: $CALLBACK:tt
+    ) => {
+        impl_builder!(
+            @setter_names
+            setter_prefix: $SETTER_PREFIX,
+            setter_suffix: $SETTER_SUFFIX,
+            fields: [ $( $FIELDS )* ],
+            names: [ $( $NAMES )* ],
+            then: $CALLBACK
+        );
+    };
+    (
+        @setter_names
+        setter_prefix: $SETTER_PREFIX:tt,
+        setter_suffix: $SETTER_SUFFIX:tt,
+        fields: [
+            { req: false, no_setter: true, setter: $F_SETTER:tt, name: $F_NAME:ident }
+            $( $FIELDS:tt )*
+        ],
+        names: [ $( $NAMES:tt )* ],
+        then: $CALLBACK:tt
+    ) => {
+        impl_builder!(
+            @setter_names
+            setter_prefix: $SETTER_PREFIX,
+            setter_suffix: $SETTER_SUFFIX,
+            fields: [ $( $FIELDS )* ],
+            names: [ $( $NAMES )* ],
+            then: $CALLBACK
+        );
+    };
+    (
+        @setter_names
+        setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+        setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+        fields: [
+            { req: false, no_setter: false, setter: [], name: $F_NAME:ident }
+            $( $FIELDS:tt )*
+        ],
+        names: [ $( $NAMES:tt )* ],
+        then: $CALLBACK:tt
+    ) => {
+        impl_builder!(
+            @setter_names
+            setter_prefix: [ $( $SETTER_PREFIX )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX )* ],
+            fields: [ $( $FIELDS )* ],
+            names: [ $( $NAMES )* [< $( $SETTER_PREFIX )* $F_NAME $( $SETTER_SUFFIX )* >] ],
+            then: $CALLBACK
+        );
+    };
+    (
+        @setter_names
+        setter_prefix: $SETTER_PREFIX:tt,
+        setter_suffix: $SETTER_SUFFIX:tt,
+        fields: [
+            { req: false, no_setter: false, setter: [ $SETTER:ident ], name: $F_NAME:ident }
+            $( $FIELDS:tt )*
+        ],
+        names: [ $( $NAMES:tt )* ],
+        then: $CALLBACK:tt
+    ) => {
+        impl_builder!(
+            @setter_names
+            setter_prefix: $SETTER_PREFIX,
+            setter_suffix: $SETTER_SUFFIX,
+            fields: [ $( $FIELDS )* ],
+            names: [ $( $NAMES )* $SETTER ],
+            then: $CALLBACK
+        );
+    };
+    (
+        @setter_names
+        setter_prefix: $SETTER_PREFIX:tt,
+        setter_suffix: $SETTER_SUFFIX:tt,
+        fields: [],
+        names: [ $( $NAMES:tt )* ],
+        then: { $( $CALLBACK:tt )* }
+    ) => {
+        $crate::paste::paste! {
+            impl_builder! { $( $CALLBACK )* setters: [ $( $NAMES )* ] }
+        }
+    };
+
+    // Generates a method of the builder, unless a setter has the same name, as the method would
+    // clash with the setter
+    (
+        @unless_setter reset $METHOD:tt setters: [ reset $( $SETTER:ident )* ]
+    ) => ();
+    (
+        @unless_setter validate $METHOD:tt setters: [ validate $( $SETTER:ident )* ]
+    ) => ();
+    (
+        @unless_setter merge $METHOD:tt setters: [ merge $( $SETTER:ident )* ]
+    ) => ();
+    (
+        @unless_setter $NAME:ident $METHOD:tt setters: [ $HEAD:ident $( $SETTER:ident )* ]
+    ) => {
+        impl_builder! { @unless_setter $NAME $METHOD setters: [ $( $SETTER )* ] }
+    };
+    (
+        @unless_setter $NAME:ident { $( $METHOD:tt )* } setters: []
+    ) => {
+        $( $METHOD )*
+    };
+
+    // Setters must not clash with the other methods generated for the builder, which would only
+    // fail with a duplicate definition. The generated names are collected into the arms of a local
+    // macro, which is then invoked with every setter. The `$` token is passed in, as the local
+    // macro needs a metavariable of its own.
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt,
+        optional: [ { req: true, name: $F_NAME:ident } $( $OPTIONAL:tt )* ],
+        nested: $NESTED:tt,
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: $SOURCES,
+            fields: $FIELDS,
+            optional: [ $( $OPTIONAL )* ],
+            nested: $NESTED,
+            generated: [ $( $GENERATED )* ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt,
+        optional: [ { req: false, name: $F_NAME:ident } $( $OPTIONAL:tt )* ],
+        nested: $NESTED:tt,
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: $SOURCES,
+            fields: $FIELDS,
+            optional: [ $( $OPTIONAL )* ],
+            nested: $NESTED,
+            generated: [ $( $GENERATED )* [<unset_ $F_NAME>] ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt,
+        optional: [],
+        nested: [ { no_setter: true, nested: $F_NESTED:tt, name: $F_NAME:ident } $( $NESTED:tt )* ],
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: $SOURCES,
+            fields: $FIELDS,
+            optional: [],
+            nested: [ $( $NESTED )* ],
+            generated: [ $( $GENERATED )* ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt,
+        optional: [],
+        nested: [ { no_setter: false, nested: [], name: $F_NAME:ident } $( $NESTED:tt )* ],
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: $SOURCES,
+            fields: $FIELDS,
+            optional: [],
+            nested: [ $( $NESTED )* ],
+            generated: [ $( $GENERATED )* ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt,
+        optional: [],
+        nested: [ { no_setter: false, nested: [ $F_NESTED:ident ], name: $F_NAME:ident } $( $NESTED:tt )* ],
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: $SOURCES,
+            fields: $FIELDS,
+            optional: [],
+            nested: [ $( $NESTED )* ],
+            generated: [ $( $GENERATED )* [<$F_NAME _mut>] ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: $DOLLAR:tt,
+        sources: [ $SOURCES:ident ],
+        fields: [ $( $F_NAME:ident )* ],
+        optional: [],
+        nested: [],
+        generated: [ $( $GENERATED:tt )* ],
+        setters: $SETTERS:tt
+    ) => {
+        impl_builder! {
+            @check_setters
+            dollar: $DOLLAR,
+            sources: [],
+            fields: [ $( $F_NAME )* ],
+            optional: [],
+            nested: [],
+            generated: [ $( $GENERATED )* set_source $( [<$F_NAME _provenance>] )* ],
+            setters: $SETTERS
+        }
+    };
+    (
+        @check_setters
+        dollar: ($DOLLAR:tt),
+        sources: [],
+        fields: [ $( $F_NAME:ident )* ],
+        optional: [],
+        nested: [],
+        generated: [ $( $GENERATED:tt )* ],
+        setters: [ $( $SETTER:ident )* ]
+    ) => {
+        $crate::paste::paste! {
+            const _: () = {
+                // allow unused macros because there may be no setters
+                #[allow(unused_macros)]
+                macro_rules! check_setter {
+                    $(
+                        ([<get_ $F_NAME>]) => { impl_builder!(@setter_clash [<get_ $F_NAME>]); };
+                        ([<is_ $F_NAME _set>]) => {
+                            impl_builder!(@setter_clash [<is_ $F_NAME _set>]);
+                        };
+                    )*
+                    $(
+                        ($GENERATED) => { impl_builder!(@setter_clash $GENERATED); };
+                    )*
+                    ($DOLLAR setter:ident) => {};
+                }
+
+                $( check_setter!($SETTER); )*
+            };
+        }
+    };
+    (
+        @setter_clash $SETTER:ident
+    ) => {
+        compile_error!(concat!(
+            "the setter `", stringify!($SETTER), "` clashes with a method generated for the builder"
+        ));
+    };
+
+    // Resets all non-mandatory fields to their default values
+    (
+        @reset
+        variant: non_consuming,
+        setters: { $( $SETTERS:tt )* },
+        fields: {
+            $(
+                {
+                    req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
+                    default: $FIELD_DEFAULT:expr,
+                    spec: $F_NAME:ident: $F_TY:ty
+                },
+            )*
+        }
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter reset {
+                // allow dead code because the user may not need to reset the builder
+                #[allow(dead_code)]
+                /// Returns all fields that have a default value to their default value.
+                pub fn reset(&mut self) -> &mut Self {
+                    $(
+                        impl_builder!(
+                            @reset_field
+                            builder: self,
+                            req: $FIELD_REQ,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
+                            default: $FIELD_DEFAULT,
+                            spec: $F_NAME: $F_TY
+                        );
+                    )*
+                    self
+                }
+            } }
+        );
+    };
+    (
+        @reset
+        variant: consuming,
+        setters: { $( $SETTERS:tt )* },
+        fields: {
+            $(
+                {
+                    req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
+                    default: $FIELD_DEFAULT:expr,
+                    spec: $F_NAME:ident: $F_TY:ty
+                },
+            )*
+        }
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter reset {
+                // allow dead code because the user may not need to reset the builder, and unused mut
+                // because there may be no fields to reset
+                #[allow(dead_code, unused_mut)]
+                /// Returns all fields that have a default value to their default value.
+                pub fn reset(mut self) -> Self {
+                    $(
+                        impl_builder!(
+                            @reset_field
+                            builder: self,
+                            req: $FIELD_REQ,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
+                            default: $FIELD_DEFAULT,
+                            spec: $F_NAME: $F_TY
+                        );
+                    )*
+                    self
+                }
+            } }
+        );
+    };
+    // `self` is passed in, as it cannot be referred to from within a nested macro
+    (
+        @reset_field
+        builder: $SELF:ident,
+        req: false,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $SELF.$F_NAME = impl_builder!(
+            @initial_value
+            lazy: $FIELD_LAZY,
+            nested: [ $( $F_NESTED )* ],
+            default: $FIELD_DEFAULT
+        );
+    };
+    (
+        @reset_field
+        builder: $SELF:ident,
+        req: true,
+        lazy: $FIELD_LAZY:ident,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+
+    // Copies the fields that are set in another builder onto this builder. Set-once fields that
+    // are already set on this builder are marked as overwritten.
+    //
+    // Mandatory fields are skipped, as they are always set through the constructor.
+    (
+        @merge
+        variant: non_consuming,
+        setters: { $( $SETTERS:tt )* },
+        sources: $SOURCES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [
+            $( { req: $FIELD_REQ:ident, set_once: $F_SET_ONCE:ident, name: $F_NAME:ident } ),*
+        ]
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter merge {
+                // allow dead code because the user may not need to merge builders, and unused variables
+                // because there may be no fields to merge
+                #[allow(dead_code, unused_variables)]
+                /// Copies every field that is set in `other` onto this builder, leaving the other
+                /// fields untouched.
+                pub fn merge(&mut self, other: $BUILDER) -> &mut Self {
+                    $(
+                        impl_builder!(
+                            @merge_field
+                            req: $FIELD_REQ,
+                            set_once: $F_SET_ONCE,
+                            sources: $SOURCES,
+                            builder: self,
+                            other: other,
+                            field: $F_NAME
+                        );
+                    )*
+                    self
+                }
+            } }
+        );
+    };
+    (
+        @merge
+        variant: consuming,
+        setters: { $( $SETTERS:tt )* },
+        sources: $SOURCES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [
+            $( { req: $FIELD_REQ:ident, set_once: $F_SET_ONCE:ident, name: $F_NAME:ident } ),*
+        ]
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter merge {
+                // allow dead code because the user may not need to merge builders, and unused mut and
+                // variables because there may be no fields to merge
+                #[allow(dead_code, unused_mut, unused_variables)]
+                /// Copies every field that is set in `other` onto this builder, leaving the other
+                /// fields untouched.
+                pub fn merge(mut self, other: $BUILDER) -> Self {
+                    $(
+                        impl_builder!(
+                            @merge_field
+                            req: $FIELD_REQ,
+                            set_once: $F_SET_ONCE,
+                            sources: $SOURCES,
+                            builder: self,
+                            other: other,
+                            field: $F_NAME
+                        );
+                    )*
+                    self
+                }
+            } }
+        );
+    };
+    // `self` is passed in, as it cannot be referred to from within a nested macro
+    (
+        @merge_field
+        req: true,
+        set_once: $F_SET_ONCE:ident,
+        sources: $SOURCES:tt,
+        builder: $SELF:ident,
+        other: $OTHER:ident,
+        field: $F_NAME:ident
+    ) => ();
+    (
+        @merge_field
+        req: false,
+        set_once: $F_SET_ONCE:ident,
+        sources: [ $( $SOURCES:ident )* ],
+        builder: $SELF:ident,
+        other: $OTHER:ident,
+        field: $F_NAME:ident
+    ) => {
+        if $OTHER.$F_NAME.is_set() {
+            $(
+                $SELF.$SOURCES.record_source(
+                    stringify!($F_NAME),
+                    $OTHER.$SOURCES.source(stringify!($F_NAME))
+                );
+            )*
+        }
+        match $OTHER.$F_NAME {
+            $crate::FieldValue::Set(value) => {
+                $SELF.$F_NAME = impl_builder!(
+                    @set_value
+                    set_once: $F_SET_ONCE,
+                    current: $SELF.$F_NAME,
+                    value: value
+                );
+            }
+            $crate::FieldValue::Overwritten(value) => {
+                $SELF.$F_NAME = $crate::FieldValue::Overwritten(value);
+            }
+            $crate::FieldValue::Unset |
+            $crate::FieldValue::Default(_) |
+            $crate::FieldValue::Built(_) => {}
+        }
+    };
+
+    // Generate accessors for where the value of each field came from, and a method to set the
+    // source of the fields that are set next, for builders with provenance
+    (
+        @provenance
+        sources: [],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+    (
+        @provenance
+        sources: [ $SOURCES:ident ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to inspect the builder
+            #[allow(dead_code)]
+            #[doc = concat!("Returns where the value of `", stringify!($F_NAME), "` came from.")]
+            pub fn [<$F_NAME _provenance>](&self) -> $crate::Provenance {
+                self.$SOURCES.provenance(stringify!($F_NAME), &self.$F_NAME)
+            }
+        }
+    };
+    (
+        @set_source
+        variant: $VARIANT:ident,
+        sources: []
+    ) => ();
+    (
+        @set_source
+        variant: non_consuming,
+        sources: [ $SOURCES:ident ]
+    ) => {
+        // allow dead code because the user may not label where values come from
+        #[allow(dead_code)]
+        /// Sets the source that is recorded for the fields that are set from now on.
+        pub fn set_source(&mut self, source: &'static str) -> &mut Self {
+            self.$SOURCES.set_current(source);
+            self
+        }
+    };
+    (
+        @set_source
+        variant: consuming,
+        sources: [ $SOURCES:ident ]
+    ) => {
+        // allow dead code because the user may not label where values come from
+        #[allow(dead_code)]
+        /// Sets the source that is recorded for the fields that are set from now on.
+        pub fn set_source(mut self, source: &'static str) -> Self {
+            self.$SOURCES.set_current(source);
+            self
+        }
+    };
+
+    // Value of a non-mandatory field before it is set.
+    //
+    // Lazy defaults are evaluated when building the struct, so the field starts off unset.
+    (
+        @initial_value
+        lazy: false,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr
+    ) => {
+        $crate::FieldValue::Default(
+            impl_builder!(@builder_value nested: [ $( $F_NESTED )* ], value: $FIELD_DEFAULT)
+        )
+    };
+    (
+        @initial_value
+        lazy: true,
+        nested: [ $( $F_NESTED:ident )* ],
+        default: $FIELD_DEFAULT:expr
+    ) => {
+        $crate::FieldValue::Unset
+    };
+
+    // Generate getters on the struct for fields that request them, documented with the docs of
+    // the field
+    (
+        @getter
+        getter: none,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => ();
+    (
+        @getter
+        getter: ref,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        // allow dead code because the getter may only be used outside the crate
+        #[allow(dead_code)]
+        #[doc = concat!("Returns a reference to `", stringify!($F_NAME), "`.")]
+        #[doc = ""]
+        $( #[doc = $F_DOC] )*
+        pub fn $F_NAME(&self) -> &$F_TY {
+            &self.$F_NAME
+        }
+    };
+    (
+        @getter
+        getter: copy,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        // allow dead code because the getter may only be used outside the crate
+        #[allow(dead_code)]
+        #[doc = concat!("Returns a copy of `", stringify!($F_NAME), "`.")]
+        #[doc = ""]
+        $( #[doc = $F_DOC] )*
+        pub fn $F_NAME(&self) -> $F_TY {
+            self.$F_NAME
+        }
+    };
+    (
+        @getter
+        getter: str,
+        docs: [ $( #[doc = $F_DOC:expr] )* ],
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        // allow dead code because the getter may only be used outside the crate
+        #[allow(dead_code)]
+        #[doc = concat!("Returns `", stringify!($F_NAME), "` as a `&str`.")]
+        #[doc = ""]
+        $( #[doc = $F_DOC] )*
+        pub fn $F_NAME(&self) -> &str {
+            &self.$F_NAME
+        }
+    };
+
+    // Generate withers on the struct, which change a field by going through the builder so that the
+    // assertions are checked again. Fields without a setter cannot be changed after construction.
+    (
+        @wither
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        no_setter: true,
+        nested: [ $( $F_NESTED:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field: $F_NAME:ident: $F_TY:ty
+    ) => ();
+    (
+        @wither
+        purpose: data,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        no_setter: false,
+        nested: [ $( $F_NESTED:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to change the struct
+            #[allow(dead_code)]
+            #[doc = concat!("Returns this struct with `", stringify!($F_NAME), "` set to `value`.")]
+            #[doc = ""]
+            #[doc = "Returns an error if the changed struct fails the assertions."]
+            pub fn [<with_ $F_NAME>](self, value: $F_TY) -> Result<$STRUCT, $crate::BuildError> {
+                let mut builder = $BUILDER::from(self);
+                builder.$F_NAME = $crate::FieldValue::Set(
+                    impl_builder!(@builder_value nested: [ $( $F_NESTED )* ], value: value)
+                );
+                impl_builder!(
+                    @call_build
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    spec: $BUILDER -> $STRUCT,
+                    builder: builder
+                )
+            }
+        }
+    };
+    (
+        @wither
+        purpose: object,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        no_setter: false,
+        nested: [ $( $F_NESTED:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to change the struct
+            #[allow(dead_code)]
+            #[doc = concat!("Returns this struct with `", stringify!($F_NAME), "` set to `value`.")]
+            #[doc = ""]
+            #[doc = "Panics if the changed struct fails the assertions."]
+            pub fn [<with_ $F_NAME>](self, value: $F_TY) -> $STRUCT {
+                let mut builder = $BUILDER::from(self);
+                builder.$F_NAME = $crate::FieldValue::Set(
+                    impl_builder!(@builder_value nested: [ $( $F_NESTED )* ], value: value)
+                );
+                impl_builder!(
+                    @call_build
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    spec: $BUILDER -> $STRUCT,
+                    builder: builder
+                )
+            }
+        }
+    };
+
+    // Calls the build method of the builder.
+    //
+    // The build method is called using its path, because the inherent method must be called even if
+    // it has the same name as the `Builder` trait's method.
+    (
+        @call_build
+        variant: non_consuming,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        builder: $BUILDER_EXPR:expr
+    ) => {
+        $BUILDER::$BUILD(&$BUILDER_EXPR)
+    };
+    (
+        @call_build
+        variant: consuming,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        builder: $BUILDER_EXPR:expr
+    ) => {
+        $BUILDER::$BUILD($BUILDER_EXPR)
+    };
+
+    // Implement `Default` for the builder and struct of `#[builder(impl_default)]` structs, which
+    // may not have required fields, as the constructor must not take any parameters
+    (
+        @default
+        impl_default: false,
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        names: $NAMES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: $FIELDS:tt
+    ) => ();
+    (
+        @default
+        impl_default: true,
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        names: $NAMES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: {
+            { req: true, $( $FIELD_SPEC:tt )* },
+            $( $FIELDS_SPEC:tt )*
+        }
+    ) => {
+        compile_error!(concat!("`#[builder(impl_default)]` struct cannot have required fields: '",
+                               stringify!($STRUCT), "'"));
+    };
+    (
+        @default
+        impl_default: true,
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        names: $NAMES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: {
+            { req: false, $( $FIELD_SPEC:tt )* },
+            $( $FIELDS_SPEC:tt )*
+        }
+    ) => {
+        impl_builder!(
+            @default
+            impl_default: true,
+            purpose: $PURPOSE,
+            variant: $VARIANT,
+            names: $NAMES,
+            spec: $BUILDER -> $STRUCT,
+            fields: {
+                $( $FIELDS_SPEC )*
+            }
+        );
+    };
+    (
+        @default
+        impl_default: true,
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        names: {
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: $SETTER_PREFIX:tt,
+            setter_suffix: $SETTER_SUFFIX:tt
+        },
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: {}
+    ) => {
+        impl ::std::default::Default for $BUILDER {
+            fn default() -> Self {
+                $BUILDER::$CONSTRUCTOR()
+            }
+        }
+
+        impl ::std::default::Default for $STRUCT {
+            fn default() -> Self {
+                impl_builder!(
+                    @default_build
+                    purpose: $PURPOSE,
+                    result: impl_builder!(
+                        @call_build
+                        variant: $VARIANT,
+                        build: $BUILD,
+                        spec: $BUILDER -> $STRUCT,
+                        builder: $BUILDER::$CONSTRUCTOR()
+                    )
+                )
+            }
+        }
+    };
+    // Data structs panic in `default()` if the builder fails to build the struct, just like object
+    // structs do
+    (
+        @default_build
+        purpose: data,
+        result: $RESULT:expr
+    ) => {
+        match $RESULT {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    };
+    (
+        @default_build
+        purpose: object,
+        result: $RESULT:expr
+    ) => {
+        $RESULT
+    };
+
+    // Conversions from the struct back into the builder
+    (
+        @to_builder
+        variant: non_consuming,
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( { nested: [ $( $F_NESTED:ident )* ], name: $F_NAME:ident } ),* ]
+    ) => {
+        impl_builder!(
+            @to_builder
+            variant: consuming,
+            sources: [ $( $SOURCES )* ],
+            spec: $BUILDER -> $STRUCT,
+            fields: [ $( { nested: [ $( $F_NESTED )* ], name: $F_NAME } ),* ]
+        );
+
+        impl $STRUCT {
+            // allow dead code because the user may not need to convert the struct into a builder
+            #[allow(dead_code)]
+            /// Returns a builder with the values of this struct
+            pub fn to_builder(&self) -> $BUILDER {
+                $BUILDER {
+                    $(
+                        $F_NAME: $crate::FieldValue::Built(impl_builder!(
+                            @cloned_builder_value
+                            nested: [ $( $F_NESTED )* ],
+                            value: self.$F_NAME
+                        )),
+                    )*
+                    $( $SOURCES: ::std::default::Default::default(), )*
+                }
+            }
+        }
+    };
+    (
+        @to_builder
+        variant: consuming,
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( { nested: [ $( $F_NESTED:ident )* ], name: $F_NAME:ident } ),* ]
+    ) => {
+        impl ::std::convert::From<$STRUCT> for $BUILDER {
+            fn from(value: $STRUCT) -> Self {
+                $BUILDER {
+                    $(
+                        $F_NAME: $crate::FieldValue::Built(impl_builder!(
+                            @builder_value
+                            nested: [ $( $F_NESTED )* ],
+                            value: value.$F_NAME
+                        )),
+                    )*
+                    $( $SOURCES: ::std::default::Default::default(), )*
+                }
+            }
+        }
+    };
+
+    // Structs with a patch can apply it, which goes through the builder so that the assertions are
+    // checked again, and compute the patch that turns them into another struct
+    (
+        @patch
+        patch: false,
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        sources: $SOURCES:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( {
+            no_setter: $F_NO_SETTER:ident,
+            nested: [ $( $F_NESTED:ident )* ],
+            spec: $F_NAME:ident: $F_TY:ty
+        } ),* ]
+    ) => ();
+    (
+        @patch
+        patch: true,
+        purpose: data,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( {
+            no_setter: $F_NO_SETTER:ident,
+            nested: [ $( $F_NESTED:ident )* ],
+            spec: $F_NAME:ident: $F_TY:ty
+        } ),* ]
+    ) => {
+        $crate::paste::paste! {
+            impl $STRUCT {
+                // allow dead code because the user may not need to patch the struct
+                #[allow(dead_code)]
+                /// Changes the fields that are set in `patch`.
+                ///
+                /// Returns an error and leaves this struct unchanged if the changed struct fails
+                /// the assertions.
+                pub fn apply(&mut self, patch: [<$STRUCT Patch>])
+                    -> Result<(), $crate::BuildError>
+                {
+                    let builder = $BUILDER {
+                        $(
+                            $F_NAME: impl_builder!(
+                                @patched_value
+                                no_setter: $F_NO_SETTER,
+                                nested: [ $( $F_NESTED )* ],
+                                patch: patch.$F_NAME,
+                                value: self.$F_NAME
+                            ),
+                        )*
+                        $( $SOURCES: ::std::default::Default::default(), )*
+                    };
+                    *self = impl_builder!(
+                        @call_build
+                        variant: $VARIANT,
+                        build: $BUILD,
+                        spec: $BUILDER -> $STRUCT,
+                        builder: builder
+                    )?;
+                    Ok(())
+                }
+
+                impl_builder!(
+                    @diff
+                    spec: $STRUCT,
+                    fields: [ $( { no_setter: $F_NO_SETTER, name: $F_NAME } )* ],
+                    patch_fields: []
+                );
+            }
+        }
+    };
+    (
+        @patch
+        patch: true,
+        purpose: object,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        sources: [ $( $SOURCES:ident )* ],
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( {
+            no_setter: $F_NO_SETTER:ident,
+            nested: [ $( $F_NESTED:ident )* ],
+            spec: $F_NAME:ident: $F_TY:ty
+        } ),* ]
+    ) => {
+        $crate::paste::paste! {
+            impl $STRUCT {
+                // allow dead code because the user may not need to patch the struct
+                #[allow(dead_code)]
+                /// Changes the fields that are set in `patch`.
+                ///
+                /// Panics if the changed struct fails the assertions.
+                pub fn apply(&mut self, patch: [<$STRUCT Patch>]) {
+                    let builder = $BUILDER {
+                        $(
+                            $F_NAME: impl_builder!(
+                                @patched_value
+                                no_setter: $F_NO_SETTER,
+                                nested: [ $( $F_NESTED )* ],
+                                patch: patch.$F_NAME,
+                                value: self.$F_NAME
+                            ),
+                        )*
+                        $( $SOURCES: ::std::default::Default::default(), )*
+                    };
+                    *self = impl_builder!(
+                        @call_build
+                        variant: $VARIANT,
+                        build: $BUILD,
+                        spec: $BUILDER -> $STRUCT,
+                        builder: builder
+                    );
+                }
+
+                impl_builder!(
+                    @diff
+                    spec: $STRUCT,
+                    fields: [ $( { no_setter: $F_NO_SETTER, name: $F_NAME } )* ],
+                    patch_fields: []
+                );
+            }
+        }
+    };
+    // Fields without a setter are not part of the patch, so they keep their value
+    (
+        @patched_value
+        no_setter: true,
+        nested: [ $( $F_NESTED:ident )* ],
+        patch: $PATCH_VALUE:expr,
+        value: $VALUE:expr
+    ) => {
+        $crate::FieldValue::Built(impl_builder!(
+            @cloned_builder_value
+            nested: [ $( $F_NESTED )* ],
+            value: $VALUE
+        ))
+    };
+    (
+        @patched_value
+        no_setter: false,
+        nested: [ $( $F_NESTED:ident )* ],
+        patch: $PATCH_VALUE:expr,
+        value: $VALUE:expr
+    ) => {
+        match $PATCH_VALUE {
+            Some(value) => $crate::FieldValue::Set(impl_builder!(
+                @builder_value
+                nested: [ $( $F_NESTED )* ],
+                value: value
+            )),
+            None => $crate::FieldValue::Built(impl_builder!(
+                @cloned_builder_value
+                nested: [ $( $F_NESTED )* ],
+                value: $VALUE
+            )),
+        }
+    };
+    (
+        @diff
+        spec: $STRUCT:ident,
+        fields: [ { no_setter: true, name: $F_NAME:ident } $( $FIELDS:tt )* ],
+        patch_fields: [ $( $PATCH_FIELDS:ident )* ]
+    ) => {
+        impl_builder!(
+            @diff
+            spec: $STRUCT,
+            fields: [ $( $FIELDS )* ],
+            patch_fields: [ $( $PATCH_FIELDS )* ]
+        );
+    };
+    (
+        @diff
+        spec: $STRUCT:ident,
+        fields: [ { no_setter: false, name: $F_NAME:ident } $( $FIELDS:tt )* ],
+        patch_fields: [ $( $PATCH_FIELDS:ident )* ]
+    ) => {
+        impl_builder!(
+            @diff
+            spec: $STRUCT,
+            fields: [ $( $FIELDS )* ],
+            patch_fields: [ $( $PATCH_FIELDS )* $F_NAME ]
+        );
+    };
+    (
+        @diff
+        spec: $STRUCT:ident,
+        fields: [],
+        patch_fields: [ $( $F_NAME:ident )* ]
+    ) => {
+        $crate::paste::paste! {
+            // allow dead code because the user may not need to compare structs
+            #[allow(dead_code)]
+            /// Returns the patch that changes this struct into `other`, which only sets the fields
+            /// that differ.
+            pub fn diff(&self, other: &$STRUCT) -> [<$STRUCT Patch>] {
+                [<$STRUCT Patch>] {
+                    $(
+                        $F_NAME: if self.$F_NAME != other.$F_NAME {
+                            Some(other.$F_NAME.clone())
+                        } else {
+                            None
+                        },
+                    )*
+                }
+            }
+        }
+    };
+
+    // Checks the assertions without keeping the struct.
+    //
+    // The builder's method builds the struct from clones of its fields and discards it, and the
+    // struct's method builds it from a new builder. Consuming builders and their structs only have
+    // the methods with `#[builder(validate)]`, as their fields and the struct must then implement
+    // `Clone`: the builder builds the struct through a private non-consuming build method.
+    (
+        @validate
+        validate: $VALIDATE:ident,
+        purpose: data,
+        variant: non_consuming,
+        build: $BUILD:ident,
+        setters: { $( $SETTERS:tt )* },
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt
+        $(, assertions: $ASSERTIONS:tt )*
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter validate {
+                impl $BUILDER {
+                    // allow dead code because the user may not need to validate the builder
+                    #[allow(dead_code)]
+                    /// Checks whether the struct can be built from this builder.
+                    ///
+                    /// This builds the struct and discards it, so lazy defaults are evaluated and
+                    /// nested structs are built.
+                    pub fn validate(&self) -> Result<(), $crate::BuildError> {
+                        $BUILDER::$BUILD(self).map(|_| ())
+                    }
+                }
+            } }
+        );
+
+        impl $STRUCT {
+            // allow dead code because the user may not need to validate the struct
+            #[allow(dead_code)]
+            /// Checks the assertions against the current values of this struct.
+            pub fn validate(&self) -> Result<(), $crate::BuildError> {
+                $BUILDER::$BUILD(&self.to_builder()).map(|_| ())
+            }
+        }
+    };
+    (
+        @validate
+        validate: $VALIDATE:ident,
+        purpose: object,
+        variant: non_consuming,
+        build: $BUILD:ident,
+        setters: { $( $SETTERS:tt )* },
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt
+        $(, assertions: $ASSERTIONS:tt )*
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter validate {
+                impl $BUILDER {
+                    // allow dead code because the user may not need to validate the builder
+                    #[allow(dead_code)]
+                    /// Checks whether the struct can be built from this builder.
+                    ///
+                    /// This builds the struct and discards it, so lazy defaults are evaluated and
+                    /// nested structs are built. Panics if an assertion fails.
+                    pub fn validate(&self) {
+                        $BUILDER::$BUILD(self);
+                    }
+                }
+            } }
+        );
+
+        impl $STRUCT {
+            // allow dead code because the user may not need to validate the struct
+            #[allow(dead_code)]
+            /// Checks the assertions against the current values of this struct.
+            ///
+            /// Panics if an assertion fails.
+            pub fn validate(&self) {
+                $BUILDER::$BUILD(&self.to_builder());
+            }
+        }
+    };
+    (
+        @validate
+        validate: false,
+        purpose: $PURPOSE:ident,
+        variant: consuming,
+        build: $BUILD:ident,
+        setters: $SETTERS:tt,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt
+        $(, assertions: $ASSERTIONS:tt )*
+    ) => ();
+    (
+        @validate
+        validate: true,
+        purpose: data,
+        variant: consuming,
+        build: $BUILD:ident,
+        setters: { $( $SETTERS:tt )* },
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt
+        $(, assertions: $ASSERTIONS:tt )*
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter validate {
+                $crate::paste::paste! {
+                    impl $BUILDER {
+                        impl_builder!(
+                            @build
+                            build: [<__ $BUILD _by_ref>],
+                            vis: [],
+                            purpose: data,
+                            variant: non_consuming,
+                            spec: $BUILDER -> $STRUCT,
+                            field_groups: $FIELD_GROUPS,
+                            sources: $SOURCES,
+                            fields: $FIELDS
+                            $(, assertions: $ASSERTIONS )*
+                        );
+
+                        // allow dead code because the user may not need to validate the builder
+                        #[allow(dead_code)]
+                        /// Checks whether the struct can be built from this builder, without
+                        /// consuming it.
+                        ///
+                        /// This builds the struct from clones of the fields and discards it, so
+                        /// lazy defaults are evaluated and nested structs are built.
+                        pub fn validate(&self) -> Result<(), $crate::BuildError> {
+                            self.[<__ $BUILD _by_ref>]().map(|_| ())
+                        }
+                    }
+                }
+            } }
+        );
+
+        impl $STRUCT {
+            // allow dead code because the user may not need to validate the struct
+            #[allow(dead_code)]
+            /// Checks the assertions against the current values of this struct.
+            ///
+            /// This builds a clone of the struct, so the struct must implement `Clone`.
+            pub fn validate(&self) -> Result<(), $crate::BuildError> {
+                $BUILDER::$BUILD($BUILDER::from(self.clone())).map(|_| ())
+            }
+        }
+    };
+    (
+        @validate
+        validate: true,
+        purpose: object,
+        variant: consuming,
+        build: $BUILD:ident,
+        setters: { $( $SETTERS:tt )* },
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: $FIELDS:tt
+        $(, assertions: $ASSERTIONS:tt )*
+    ) => {
+        impl_builder!(
+            @setter_names
+            $( $SETTERS )*,
+            names: [],
+            then: { @unless_setter validate {
+                $crate::paste::paste! {
+                    impl $BUILDER {
+                        impl_builder!(
+                            @build
+                            build: [<__ $BUILD _by_ref>],
+                            vis: [],
+                            purpose: object,
+                            variant: non_consuming,
+                            spec: $BUILDER -> $STRUCT,
+                            field_groups: $FIELD_GROUPS,
+                            sources: $SOURCES,
+                            fields: $FIELDS
+                            $(, assertions: $ASSERTIONS )*
+                        );
+
+                        // allow dead code because the user may not need to validate the builder
+                        #[allow(dead_code)]
+                        /// Checks whether the struct can be built from this builder, without
+                        /// consuming it.
+                        ///
+                        /// This builds the struct from clones of the fields and discards it, so
+                        /// lazy defaults are evaluated and nested structs are built. Panics if an
+                        /// assertion fails.
+                        pub fn validate(&self) {
+                            self.[<__ $BUILD _by_ref>]();
+                        }
+                    }
+                }
+            } }
+        );
+
+        impl $STRUCT {
+            // allow dead code because the user may not need to validate the struct
+            #[allow(dead_code)]
+            /// Checks the assertions against the current values of this struct.
+            ///
+            /// This builds a clone of the struct, so the struct must implement `Clone`. Panics if
+            /// an assertion fails.
+            pub fn validate(&self) {
+                $BUILDER::$BUILD($BUILDER::from(self.clone()));
+            }
+        }
+    };
+
+    // Implement the `Builder` trait, and `TryFrom<Builder>` for data structs
+    (
+        @builder_trait
+        purpose: data,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident
+    ) => {
+        impl $crate::Builder for $BUILDER {
+            type Output = $STRUCT;
+            type Error = $crate::BuildError;
+
+            fn try_build(self) -> Result<$STRUCT, $crate::BuildError> {
+                impl_builder!(
+                    @call_build
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    spec: $BUILDER -> $STRUCT,
+                    builder: self
+                )
+            }
+        }
+
+        impl ::std::convert::TryFrom<$BUILDER> for $STRUCT {
+            type Error = $crate::BuildError;
+
+            fn try_from(builder: $BUILDER) -> Result<$STRUCT, $crate::BuildError> {
+                impl_builder!(
+                    @call_build
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    spec: $BUILDER -> $STRUCT,
+                    builder: builder
+                )
+            }
+        }
+
+        impl_builder!(
+            @builder_ref_trait
+            purpose: data,
+            variant: $VARIANT,
+            build: $BUILD,
+            spec: $BUILDER -> $STRUCT
+        );
+    };
+    (
+        @builder_trait
+        purpose: object,
+        variant: $VARIANT:ident,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident
+    ) => {
+        impl $crate::Builder for $BUILDER {
+            type Output = $STRUCT;
+            type Error = ::std::convert::Infallible;
+
+            fn try_build(self) -> Result<$STRUCT, ::std::convert::Infallible> {
+                Ok(impl_builder!(
+                    @call_build
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    spec: $BUILDER -> $STRUCT,
+                    builder: self
+                ))
+            }
+        }
+
+        impl_builder!(
+            @builder_ref_trait
+            purpose: object,
+            variant: $VARIANT,
+            build: $BUILD,
+            spec: $BUILDER -> $STRUCT
+        );
+    };
+    // Non-consuming builders also implement the `Builder` trait for references, which builds the
+    // struct without consuming the builder
+    (
+        @builder_ref_trait
+        purpose: $PURPOSE:ident,
+        variant: consuming,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident
+    ) => ();
+    (
+        @builder_ref_trait
+        purpose: data,
+        variant: non_consuming,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident
+    ) => {
+        impl<'a> $crate::Builder for &'a $BUILDER {
+            type Output = $STRUCT;
+            type Error = $crate::BuildError;
+
+            fn try_build(self) -> Result<$STRUCT, $crate::BuildError> {
+                $BUILDER::$BUILD(self)
+            }
+        }
+    };
+    (
+        @builder_ref_trait
+        purpose: object,
+        variant: non_consuming,
+        build: $BUILD:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident
+    ) => {
+        impl<'a> $crate::Builder for &'a $BUILDER {
+            type Output = $STRUCT;
+            type Error = ::std::convert::Infallible;
+
+            fn try_build(self) -> Result<$STRUCT, ::std::convert::Infallible> {
+                Ok($BUILDER::$BUILD(self))
+            }
+        }
+    };
+
+    // Retrieve the value for a field when building the struct.
+    // Lazy defaults are only evaluated if the field has not been set.
+    //
+    // Non-consuming builders clone the value, unless the field is nested, in which case the struct
+    // is built from a reference to the nested builder.
+    (
+        @value
+        purpose: $PURPOSE:ident,
+        variant: non_consuming,
+        source: $SOURCE:expr,
+        lazy: $FIELD_LAZY:ident,
+        nested: [],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        impl_builder!(
+            @value
+            purpose: $PURPOSE,
+            variant: consuming,
+            source: $SOURCE.clone(),
+            lazy: $FIELD_LAZY,
+            nested: [],
+            default: $FIELD_DEFAULT,
+            spec: $F_NAME: $F_TY
+        )
+    };
+    // For data structs, lazy defaults are evaluated in a closure that returns a `Result` so that
+    // the default expression may use the `?` operator.
+    (
+        @value
+        purpose: data,
+        variant: consuming,
+        source: $SOURCE:expr,
+        lazy: true,
+        nested: [],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        match $SOURCE.into_value() {
+            Some(value) => value,
+            None => {
+                let default = || -> Result<$F_TY, Box<dyn std::error::Error + Send + Sync>> {
+                    Ok($FIELD_DEFAULT)
+                };
+                default().map_err(|error| {
+                    $crate::BuildError::DefaultFailed {
+                        field: stringify!($F_NAME),
+                        source: error,
+                    }
+                })?
+            }
+        }
+    };
+    (
+        @value
+        purpose: object,
+        variant: consuming,
+        source: $SOURCE:expr,
+        lazy: true,
+        nested: [],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        match $SOURCE.into_value() {
+            Some(value) => value,
+            None => $FIELD_DEFAULT,
+        }
+    };
+    (
+        @value
+        purpose: $PURPOSE:ident,
+        variant: consuming,
+        source: $SOURCE:expr,
+        lazy: false,
+        nested: [],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $SOURCE.into_value().unwrap()
+    };
+    // Nested structs are built through the `Builder` trait, which is implemented for references to
+    // their non-consuming builders, so that their build method may have any name.
+    //
+    // Errors from building nested structs are wrapped with the name of the field, so that the path
+    // to the field that failed can be reported
+    (
+        @value
+        purpose: data,
+        variant: $VARIANT:ident,
+        source: $SOURCE:expr,
+        lazy: false,
+        nested: [ $NESTED:ident ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        $crate::Builder::try_build($SOURCE.value().unwrap()).map_err(|error| {
+            $crate::BuildError::Nested {
+                field: stringify!($F_NAME),
+                source: Box::new(error),
+            }
+        })?
+    };
+    (
+        @value
+        purpose: object,
+        variant: $VARIANT:ident,
+        source: $SOURCE:expr,
+        lazy: false,
+        nested: [ $NESTED:ident ],
+        default: $FIELD_DEFAULT:expr,
+        spec: $F_NAME:ident: $F_TY:ty
+    ) => {
+        match $crate::Builder::try_build($SOURCE.value().unwrap()) {
+            Ok(value) => value,
+            Err(error) => match error {},
+        }
+    };
+
+    // Converts a value of a field into the value stored in the builder
+    (
+        @builder_value
+        nested: [],
+        value: $VALUE:expr
+    ) => {
+        $VALUE
+    };
+    (
+        @builder_value
+        nested: [ $NESTED:ident ],
+        value: $VALUE:expr
+    ) => {
+        $NESTED::from($VALUE)
+    };
+
+    // Clones a value of a field into the value stored in the builder. The nested struct does not
+    // have to implement `Clone`, as it has a non-consuming builder.
+    (
+        @cloned_builder_value
+        nested: [],
+        value: $VALUE:expr
+    ) => {
+        $VALUE.clone()
+    };
+    (
+        @cloned_builder_value
+        nested: [ $NESTED:ident ],
+        value: $VALUE:expr
+    ) => {
+        $VALUE.to_builder()
+    };
+
+
+    // Checks that no set-once field was set more than once.
+    //
+    // `self` is passed in, as it cannot be referred to from within a nested macro.
+    (
+        @check_overwritten
+        purpose: $PURPOSE:ident,
+        builder: $SELF:ident,
+        fields: [ $( $F_NAME:ident ),* ]
+    ) => {
+        $(
+            if $SELF.$F_NAME.is_overwritten() {
+                impl_builder!(
+                    @build_failed
+                    purpose: $PURPOSE,
+                    error: $crate::BuildError::FieldOverwritten { field: stringify!($F_NAME) }
+                );
+            }
+        )*
+    };
+
+    // Checks that each field group has an allowed combination of its fields set, and that
+    // conditionally required fields are set.
+    //
+    // Values of a struct that was converted back into the builder do not count as set, so a group
+    // whose fields all still have those values is not checked again. Once a field of the group is
+    // set, the converted values that differ from their defaults count as set too.
+    //
+    // `self` is passed in, as it cannot be referred to from within a nested macro.
+    (
+        @check_field_groups
+        purpose: $PURPOSE:ident,
+        builder: $SELF:ident,
+        field_groups: [
+            $( { kind: $GROUP_KIND:ident, fields: [ $( $GROUP_FIELD:ident ),+ ] } )*
+        ]
+    ) => {
+        $(
+            impl_builder!(
+                @check_field_group
+                purpose: $PURPOSE,
+                builder: $SELF,
+                kind: $GROUP_KIND,
+                fields: [ $( $GROUP_FIELD ),+ ]
+            );
+        )*
+    };
+    // The condition field of `required_if` is only true if its value is known before building,
+    // so unset lazy fields are treated as false, and it is not checked again if the value came
+    // from a converted struct. The required field is satisfied by the value of a converted struct.
+    (
+        @check_field_group
+        purpose: $PURPOSE:ident,
+        builder: $SELF:ident,
+        kind: required_if,
+        fields: [ $FIELD:ident, $CONDITION:ident ]
+    ) => {
+        {
+            let condition = !$SELF.$CONDITION.is_built()
+                && $SELF.$CONDITION.value().map_or(false, |condition| *condition);
+            if !$SELF.$FIELD.is_set() && !$SELF.$FIELD.is_built() && condition {
+                impl_builder!(
+                    @build_failed
+                    purpose: $PURPOSE,
+                    error: $crate::BuildError::FieldRequired {
+                        field: stringify!($FIELD),
+                        required_by: stringify!($CONDITION),
+                        when: $crate::RequiredWhen::True,
+                    }
+                );
+            }
+        }
+    };
+    (
+        @check_field_group
+        purpose: $PURPOSE:ident,
+        builder: $SELF:ident,
+        kind: required_with,
+        fields: [ $FIELD:ident, $CONDITION:ident ]
+    ) => {
+        if !$SELF.$FIELD.is_set() && !$SELF.$FIELD.is_built() && $SELF.$CONDITION.is_set() {
+            impl_builder!(
+                @build_failed
+                purpose: $PURPOSE,
+                error: $crate::BuildError::FieldRequired {
+                    field: stringify!($FIELD),
+                    required_by: stringify!($CONDITION),
+                    when: $crate::RequiredWhen::Set,
+                }
+            );
+        }
+    };
+    (
+        @check_field_group
+        purpose: $PURPOSE:ident,
+        builder: $SELF:ident,
+        kind: $GROUP_KIND:ident,
+        fields: [ $( $GROUP_FIELD:ident ),+ ]
+    ) => {
+        {
+            let set = [ $( $SELF.$GROUP_FIELD.is_set() ),+ ];
+            let mut set_count = set.iter().filter(|field_set| **field_set).count();
+            let built = [ $( $SELF.$GROUP_FIELD.is_built() ),+ ]
+                .iter()
+                .any(|field_built| *field_built);
+            if set_count > 0 {
+                let changed = $crate::paste::paste! {
+                    [
+                        $(
+                            $SELF.$GROUP_FIELD.is_built()
+                                && !Self::[<__ $GROUP_FIELD _is_default>](&$SELF.$GROUP_FIELD)
+                        ),+
+                    ]
+                };
+                set_count += changed.iter().filter(|field_changed| **field_changed).count();
+            }
+            let satisfied = (set_count == 0 && built) || impl_builder!(
+                @field_group_satisfied
+                kind: $GROUP_KIND,
+                set_count: set_count,
+                field_count: set.len()
+            );
+            if !satisfied {
+                impl_builder!(
+                    @build_failed
+                    purpose: $PURPOSE,
+                    error: $crate::BuildError::FieldGroup {
+                        kind: impl_builder!(@field_group_kind $GROUP_KIND),
+                        fields: &[ $( stringify!($GROUP_FIELD) ),+ ],
+                    }
+                );
+            }
+        }
+    };
+    (
+        @field_group_satisfied
+        kind: exactly_one,
+        set_count: $SET_COUNT:expr,
+        field_count: $FIELD_COUNT:expr
+    ) => {
+        $SET_COUNT == 1
+    };
+    (
+        @field_group_satisfied
+        kind: at_most_one,
+        set_count: $SET_COUNT:expr,
+        field_count: $FIELD_COUNT:expr
+    ) => {
+        $SET_COUNT <= 1
+    };
+    (
+        @field_group_satisfied
+        kind: at_least_one,
+        set_count: $SET_COUNT:expr,
+        field_count: $FIELD_COUNT:expr
+    ) => {
+        $SET_COUNT >= 1
+    };
+    (
+        @field_group_satisfied
+        kind: all_or_none,
+        set_count: $SET_COUNT:expr,
+        field_count: $FIELD_COUNT:expr
+    ) => {
+        $SET_COUNT == 0 || $SET_COUNT == $FIELD_COUNT
+    };
+    (@field_group_kind exactly_one) => { $crate::FieldGroupKind::ExactlyOne };
+    (@field_group_kind at_most_one) => { $crate::FieldGroupKind::AtMostOne };
+    (@field_group_kind at_least_one) => { $crate::FieldGroupKind::AtLeastOne };
+    (@field_group_kind all_or_none) => { $crate::FieldGroupKind::AllOrNone };
+    // Errors from building data structs with provenance are wrapped with where the value of each
+    // field came from. The build is run in a closure, so that errors returned early are wrapped
+    // too.
+    // Object structs catch the panic instead, and panic again with the provenance appended, so that
+    // only the second panic is reported.
+    //
+    // `self` is passed in, as it cannot be referred to from within a nested macro.
+    (
+        @with_provenance
+        purpose: $PURPOSE:ident,
+        sources: [],
+        builder: $SELF:ident,
         spec: $BUILDER:ident -> $STRUCT:ident,
-        separator: [ $( $SEPARATOR:tt )* ],
-        params: [ $( { $( $PARAMS:tt )* }, )* ],
-        assignments: [ $( { $( $ASSIGNMENTS:tt )* }, )* ],
-        fields: {}
-    )
-    =>
-    {
-        /// Construct the builder
-        pub fn new( $( $( $PARAMS )* )* ) -> $BUILDER {
-            $BUILDER {
-                $( $( $ASSIGNMENTS )* )*
-            }
-        }
+        fields: [ $( $F_NAME:ident ),* ],
+        build: { $( $BUILD_BODY:tt )* }
+    ) => {
+        { $( $BUILD_BODY )* }
     };
-
-    // Generate setter for non-mandatory fields
     (
-        @setter
-        variant: non_consuming,
-        req: false,
-        default: $FIELD_DEFAULT:expr,
-        spec: $F_NAME:ident: $F_TY:ty
+        @with_provenance
+        purpose: data,
+        sources: [ $SOURCES:ident ],
+        builder: $SELF:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( $F_NAME:ident ),* ],
+        build: { $( $BUILD_BODY:tt )* }
     ) => {
-        // allow dead code because the user may be using the field default
-        #[allow(dead_code)]
-        /// Auto-generated setter
-        pub fn $F_NAME(&mut self, value: $F_TY) -> &mut Self {
-            self.$F_NAME = Some(value);
-            self
+        {
+            let fields = vec![
+                $(
+                    (
+                        stringify!($F_NAME),
+                        $SELF.$SOURCES.provenance(stringify!($F_NAME), &$SELF.$F_NAME),
+                    )
+                ),*
+            ];
+            let build = move || -> Result<$STRUCT, $crate::BuildError> { $( $BUILD_BODY )* };
+            build().map_err(|error| $crate::BuildError::WithProvenance {
+                error: Box::new(error),
+                fields,
+            })
         }
     };
     (
-        @setter
-        variant: consuming,
-        req: false,
-        default: $FIELD_DEFAULT:expr,
-        spec: $F_NAME:ident: $F_TY:ty
+        @with_provenance
+        purpose: object,
+        sources: [ $SOURCES:ident ],
+        builder: $SELF:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
+        fields: [ $( $F_NAME:ident ),* ],
+        build: { $( $BUILD_BODY:tt )* }
     ) => {
-        // allow dead code because the user may be using the field default
-        #[allow(dead_code)]
-        /// Auto-generated setter
-        pub fn $F_NAME(mut self, value: $F_TY) -> Self {
-            self.$F_NAME = Some(value);
-            self
+        {
+            let fields = vec![
+                $(
+                    (
+                        stringify!($F_NAME),
+                        $SELF.$SOURCES.provenance(stringify!($F_NAME), &$SELF.$F_NAME),
+                    )
+                ),*
+            ];
+            let build = move || -> $STRUCT { $( $BUILD_BODY )* };
+            $crate::Sources::build_with_provenance(build, &fields)
         }
     };
+
+    // Data structs return the error, and object structs panic with its message
     (
-        @setter
-        variant: $VARIANT:ident,
-        req: true,
-        default: $FIELD_DEFAULT:expr,
-        spec: $F_NAME:ident: $F_TY:ty
-    ) => ();
+        @build_failed
+        purpose: data,
+        error: $ERROR:expr
+    ) => {
+        return Err($ERROR);
+    };
+    (
+        @build_failed
+        purpose: object,
+        error: $ERROR:expr
+    ) => {
+        panic!("{}", $ERROR);
+    };
 
-    // Non-consuming
+    // Build methods.
+    //
+    // Nested macro call should be stable for format!
+    // https://github.com/rust-lang/rust/blob/1.12.0/src/libsyntax_ext/format.rs#L684-L687
     (
+        @build
+        build: $BUILD:ident,
+        vis: [ $( $BUILD_VIS:ident )* ],
         purpose: data,
         variant: non_consuming,
         spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
         fields: {
             $(
                 {
-                    req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
             )*
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
-    )
-    =>
-    {
-        impl $BUILDER {
+    ) => {
+        /// Build the struct
+        $( $BUILD_VIS )* fn $BUILD(&self) -> Result<$STRUCT, $crate::BuildError> {
             impl_builder!(
-                @constructor
+                @with_provenance
+                purpose: data,
+                sources: $SOURCES,
+                builder: self,
                 spec: $BUILDER -> $STRUCT,
-                fields: {
+                fields: [ $( $F_NAME ),* ],
+                build: {
+                    impl_builder!(
+                        @check_overwritten
+                        purpose: data,
+                        builder: self,
+                        fields: [ $( $F_NAME ),* ]
+                    );
+                    impl_builder!(
+                        @check_field_groups
+                        purpose: data,
+                        builder: self,
+                        field_groups: $FIELD_GROUPS
+                    );
+
                     $(
-                        {
-                            req: $FIELD_REQ,
+                        let $F_NAME = impl_builder!(
+                            @value
+                            purpose: data,
+                            variant: non_consuming,
+                            source: self.$F_NAME,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
                             default: $FIELD_DEFAULT,
                             spec: $F_NAME: $F_TY
-                        },
+                        );
                     )*
-                }
-            );
 
-            // Nested macro call should be stable for format!
-            // https://github.com/rust-lang/rust/blob/1.12.0/src/libsyntax_ext/format.rs#L684-L687
-            /// Build the struct
-            pub fn build(&self) -> Result<$STRUCT, &'static str> {
-                $( let $F_NAME = self.$F_NAME.clone().unwrap(); )*
-
-                $(
-                    use std::panic;
                     $(
-                        panic::catch_unwind(|| { $ASSERTION; }).or(
-                            Err(concat!("assertion failed: '", stringify!($ASSERTION), "'")) )?;
+                        use std::panic;
+                        $(
+                            panic::catch_unwind(|| { $ASSERTION; }).or(
+                                Err($crate::BuildError::AssertionFailed(concat!(
+                                    "assertion failed: '", stringify!($ASSERTION), "'"
+                                ))))?;
+                        )*
                     )*
-                )*
-
-                Ok($STRUCT {
-                    $( $F_NAME: $F_NAME ),*
-                })
-            }
 
-            $(
-                impl_builder!(
-                    @setter
-                    variant: non_consuming,
-                    req: $FIELD_REQ,
-                    default: $FIELD_DEFAULT,
-                    spec: $F_NAME: $F_TY
-                );
-            )*
+                    Ok($STRUCT {
+                        $( $F_NAME: $F_NAME ),*
+                    })
+                }
+            )
         }
     };
     (
+        @build
+        build: $BUILD:ident,
+        vis: [ $( $BUILD_VIS:ident )* ],
         purpose: object,
         variant: non_consuming,
         spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
         fields: {
             $(
                 {
-                    req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
             )*
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
-    )
-    =>
-    {
-        impl $BUILDER {
+    ) => {
+        /// Build the struct
+        $( $BUILD_VIS )* fn $BUILD(&self) -> $STRUCT {
             impl_builder!(
-                @constructor
+                @with_provenance
+                purpose: object,
+                sources: $SOURCES,
+                builder: self,
                 spec: $BUILDER -> $STRUCT,
-                fields: {
+                fields: [ $( $F_NAME ),* ],
+                build: {
+                    impl_builder!(
+                        @check_overwritten
+                        purpose: object,
+                        builder: self,
+                        fields: [ $( $F_NAME ),* ]
+                    );
+                    impl_builder!(
+                        @check_field_groups
+                        purpose: object,
+                        builder: self,
+                        field_groups: $FIELD_GROUPS
+                    );
+
                     $(
-                        {
-                            req: $FIELD_REQ,
+                        let $F_NAME = impl_builder!(
+                            @value
+                            purpose: object,
+                            variant: non_consuming,
+                            source: self.$F_NAME,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
                             default: $FIELD_DEFAULT,
                             spec: $F_NAME: $F_TY
-                        },
+                        );
                     )*
-                }
-            );
 
-            // Nested macro call should be stable for format!
-            // https://github.com/rust-lang/rust/blob/1.12.0/src/libsyntax_ext/format.rs#L684-L687
-            /// Build the struct
-            pub fn build(&self) -> $STRUCT {
-                $( let $F_NAME = self.$F_NAME.clone().unwrap(); )*
+                    $( $( $ASSERTION; )* )*
 
-                $( $( $ASSERTION; )* )*
-
-                $STRUCT {
-                    $( $F_NAME: $F_NAME ),*
+                    $STRUCT {
+                        $( $F_NAME: $F_NAME ),*
+                    }
                 }
-            }
-
-            $(
-                impl_builder!(
-                    @setter
-                    variant: non_consuming,
-                    req: $FIELD_REQ,
-                    default: $FIELD_DEFAULT,
-                    spec: $F_NAME: $F_TY
-                );
-            )*
+            )
         }
     };
-
-    // Consuming variant
     (
+        @build
+        build: $BUILD:ident,
+        vis: [ $( $BUILD_VIS:ident )* ],
         purpose: data,
         variant: consuming,
         spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
         fields: {
             $(
                 {
-                    req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
             )*
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
-    )
-    =>
-    {
-        impl $BUILDER {
+    ) => {
+        /// Build the struct
+        #[allow(unused_mut)]
+        $( $BUILD_VIS )* fn $BUILD(self) -> Result<$STRUCT, $crate::BuildError> {
             impl_builder!(
-                @constructor
+                @with_provenance
+                purpose: data,
+                sources: $SOURCES,
+                builder: self,
                 spec: $BUILDER -> $STRUCT,
-                fields: {
+                fields: [ $( $F_NAME ),* ],
+                build: {
+                    impl_builder!(
+                        @check_overwritten
+                        purpose: data,
+                        builder: self,
+                        fields: [ $( $F_NAME ),* ]
+                    );
+                    impl_builder!(
+                        @check_field_groups
+                        purpose: data,
+                        builder: self,
+                        field_groups: $FIELD_GROUPS
+                    );
+
+                    // mutability is necessary for assertions on trait fields to work, otherwise the
+                    // compiler fails with unwind safety not being satisfied
                     $(
-                        {
-                            req: $FIELD_REQ,
+                        let mut $F_NAME = impl_builder!(
+                            @value
+                            purpose: data,
+                            variant: consuming,
+                            source: self.$F_NAME,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
                             default: $FIELD_DEFAULT,
                             spec: $F_NAME: $F_TY
-                        },
+                        );
                     )*
-                }
-            );
-
-            // Nested macro call should be stable for format!
-            // https://github.com/rust-lang/rust/blob/1.12.0/src/libsyntax_ext/format.rs#L684-L687
-            /// Build the struct
-            #[allow(unused_mut)]
-            pub fn build(self) -> Result<$STRUCT, &'static str> {
-                // mutability is necessary for assertions on trait fields to work, otherwise the
-                // compiler fails with unwind safety not being satisfied
-                $( let mut $F_NAME = self.$F_NAME.unwrap(); )*
 
-                $(
-                    use std::panic::{self, AssertUnwindSafe};
                     $(
-                        panic::catch_unwind(AssertUnwindSafe(|| { $ASSERTION; })).or(
-                            Err(concat!("assertion failed: '", stringify!($ASSERTION), "'")) )?;
+                        use std::panic::{self, AssertUnwindSafe};
+                        $(
+                            panic::catch_unwind(AssertUnwindSafe(|| { $ASSERTION; })).or(
+                                Err($crate::BuildError::AssertionFailed(concat!(
+                                    "assertion failed: '", stringify!($ASSERTION), "'"
+                                ))))?;
+                        )*
                     )*
-                )*
 
-                Ok($STRUCT {
-                    $( $F_NAME: $F_NAME ),*
-                })
-            }
-
-            $(
-                impl_builder!(
-                    @setter
-                    variant: consuming,
-                    req: $FIELD_REQ,
-                    default: $FIELD_DEFAULT,
-                    spec: $F_NAME: $F_TY
-                );
-            )*
+                    Ok($STRUCT {
+                        $( $F_NAME: $F_NAME ),*
+                    })
+                }
+            )
         }
     };
     (
+        @build
+        build: $BUILD:ident,
+        vis: [ $( $BUILD_VIS:ident )* ],
         purpose: object,
         variant: consuming,
         spec: $BUILDER:ident -> $STRUCT:ident,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        fields: {
+            $(
+                {
+                    lazy: $FIELD_LAZY:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
+                    default: $FIELD_DEFAULT:expr,
+                    spec: $F_NAME:ident: $F_TY:ty
+                },
+            )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    ) => {
+        /// Build the struct
+        #[allow(unused_mut)]
+        $( $BUILD_VIS )* fn $BUILD(self) -> $STRUCT {
+            impl_builder!(
+                @with_provenance
+                purpose: object,
+                sources: $SOURCES,
+                builder: self,
+                spec: $BUILDER -> $STRUCT,
+                fields: [ $( $F_NAME ),* ],
+                build: {
+                    impl_builder!(
+                        @check_overwritten
+                        purpose: object,
+                        builder: self,
+                        fields: [ $( $F_NAME ),* ]
+                    );
+                    impl_builder!(
+                        @check_field_groups
+                        purpose: object,
+                        builder: self,
+                        field_groups: $FIELD_GROUPS
+                    );
+
+                    // mutability is necessary for assertions on trait fields to work, otherwise the
+                    // compiler fails with unwind safety not being satisfied
+                    $(
+                        let mut $F_NAME = impl_builder!(
+                            @value
+                            purpose: object,
+                            variant: consuming,
+                            source: self.$F_NAME,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
+                            default: $FIELD_DEFAULT,
+                            spec: $F_NAME: $F_TY
+                        );
+                    )*
+
+                    $( $( $ASSERTION; )* )*
+
+                    $STRUCT {
+                        $( $F_NAME: $F_NAME ),*
+                    }
+                }
+            )
+        }
+    };
+
+    (
+        purpose: $PURPOSE:ident,
+        variant: $VARIANT:ident,
+        names: {
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: $SETTER_PREFIX:tt,
+            setter_suffix: $SETTER_SUFFIX:tt
+        },
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        patch: $PATCH:ident,
+        impl_default: $IMPL_DEFAULT:ident,
+        validate: $VALIDATE:ident,
+        spec: $BUILDER:ident -> $STRUCT:ident,
         fields: {
             $(
                 {
                     req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
+                    docs: [ $( #[doc = $F_DOC:expr] )* ],
+                    setter: [ $( $F_SETTER:ident )* ],
+                    no_setter: $F_NO_SETTER:ident,
+                    getter: $F_GETTER:ident,
+                    nested: [ $( $F_NESTED:ident )* ],
+                    set_once: $F_SET_ONCE:ident,
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
@@ -345,46 +2820,289 @@ macro_rules! impl_builder {
     )
     =>
     {
+        impl_builder!(
+            @constructor
+            constructor: $CONSTRUCTOR,
+            sources: $SOURCES,
+            spec: $BUILDER -> $STRUCT,
+            fields: {
+                $(
+                    {
+                        req: $FIELD_REQ,
+                        lazy: $FIELD_LAZY,
+                        docs: [ $( #[doc = $F_DOC] )* ],
+                        nested: [ $( $F_NESTED )* ],
+                        default: $FIELD_DEFAULT,
+                        spec: $F_NAME: $F_TY
+                    },
+                )*
+            }
+        );
+
         impl $BUILDER {
             impl_builder!(
-                @constructor
+                @build
+                build: $BUILD,
+                vis: [ pub ],
+                purpose: $PURPOSE,
+                variant: $VARIANT,
                 spec: $BUILDER -> $STRUCT,
+                field_groups: $FIELD_GROUPS,
+                sources: $SOURCES,
                 fields: {
                     $(
                         {
-                            req: $FIELD_REQ,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
                             default: $FIELD_DEFAULT,
                             spec: $F_NAME: $F_TY
                         },
                     )*
                 }
+                $(, assertions: { $( $ASSERTION; )* } )*
             );
 
-            // Nested macro call should be stable for format!
-            // https://github.com/rust-lang/rust/blob/1.12.0/src/libsyntax_ext/format.rs#L684-L687
-            /// Build the struct
-            #[allow(unused_mut)]
-            pub fn build(self) -> $STRUCT {
-                // mutability is necessary for assertions on trait fields to work, otherwise the
-                // compiler fails with unwind safety not being satisfied
-                $( let mut $F_NAME = self.$F_NAME.unwrap(); )*
+            $(
+                impl_builder!(
+                    @setter
+                    variant: $VARIANT,
+                    no_setter: $F_NO_SETTER,
+                    setter: [ $( $F_SETTER )* ],
+                    setter_prefix: $SETTER_PREFIX,
+                    setter_suffix: $SETTER_SUFFIX,
+                    req: $FIELD_REQ,
+                    lazy: $FIELD_LAZY,
+                    docs: [ $( #[doc = $F_DOC] )* ],
+                    nested: [ $( $F_NESTED )* ],
+                    set_once: $F_SET_ONCE,
+                    sources: $SOURCES,
+                    default: $FIELD_DEFAULT,
+                    spec: $F_NAME: $F_TY
+                );
+
+                impl_builder!(
+                    @nested_mut
+                    variant: $VARIANT,
+                    no_setter: $F_NO_SETTER,
+                    nested: [ $( $F_NESTED )* ],
+                    sources: $SOURCES,
+                    spec: $F_NAME: $F_TY
+                );
 
-                $( $( $ASSERTION; )* )*
+                impl_builder!(
+                    @accessors
+                    variant: $VARIANT,
+                    req: $FIELD_REQ,
+                    lazy: $FIELD_LAZY,
+                    nested: [ $( $F_NESTED )* ],
+                    default: $FIELD_DEFAULT,
+                    spec: $F_NAME: $F_TY
+                );
 
-                $STRUCT {
-                    $( $F_NAME: $F_NAME ),*
-                }
-            }
+                impl_builder!(
+                    @provenance
+                    sources: $SOURCES,
+                    spec: $F_NAME: $F_TY
+                );
 
-            $(
                 impl_builder!(
-                    @setter
-                    variant: consuming,
+                    @is_default
+                    purpose: $PURPOSE,
                     req: $FIELD_REQ,
+                    lazy: $FIELD_LAZY,
+                    nested: [ $( $F_NESTED )* ],
                     default: $FIELD_DEFAULT,
                     spec: $F_NAME: $F_TY
                 );
             )*
+
+            impl_builder!(
+                @set_source
+                variant: $VARIANT,
+                sources: $SOURCES
+            );
+
+            impl_builder!(
+                @merge
+                variant: $VARIANT,
+                setters: {
+                    setter_prefix: $SETTER_PREFIX,
+                    setter_suffix: $SETTER_SUFFIX,
+                    fields: [ $( {
+                        req: $FIELD_REQ,
+                        no_setter: $F_NO_SETTER,
+                        setter: [ $( $F_SETTER )* ],
+                        name: $F_NAME
+                    } )* ]
+                },
+                sources: $SOURCES,
+                spec: $BUILDER -> $STRUCT,
+                fields: [
+                    $( { req: $FIELD_REQ, set_once: $F_SET_ONCE, name: $F_NAME } ),*
+                ]
+            );
+
+            impl_builder!(
+                @reset
+                variant: $VARIANT,
+                setters: {
+                    setter_prefix: $SETTER_PREFIX,
+                    setter_suffix: $SETTER_SUFFIX,
+                    fields: [ $( {
+                        req: $FIELD_REQ,
+                        no_setter: $F_NO_SETTER,
+                        setter: [ $( $F_SETTER )* ],
+                        name: $F_NAME
+                    } )* ]
+                },
+                fields: {
+                    $(
+                        {
+                            req: $FIELD_REQ,
+                            lazy: $FIELD_LAZY,
+                            nested: [ $( $F_NESTED )* ],
+                            default: $FIELD_DEFAULT,
+                            spec: $F_NAME: $F_TY
+                        },
+                    )*
+                }
+            );
+        }
+
+        impl $STRUCT {
+            $(
+                impl_builder!(
+                    @getter
+                    getter: $F_GETTER,
+                    docs: [ $( #[doc = $F_DOC] )* ],
+                    spec: $F_NAME: $F_TY
+                );
+
+                impl_builder!(
+                    @wither
+                    purpose: $PURPOSE,
+                    variant: $VARIANT,
+                    build: $BUILD,
+                    no_setter: $F_NO_SETTER,
+                    nested: [ $( $F_NESTED )* ],
+                    spec: $BUILDER -> $STRUCT,
+                    field: $F_NAME: $F_TY
+                );
+            )*
         }
+
+        impl_builder!(
+            @default
+            impl_default: $IMPL_DEFAULT,
+            purpose: $PURPOSE,
+            variant: $VARIANT,
+            names: {
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: $SETTER_PREFIX,
+                setter_suffix: $SETTER_SUFFIX
+            },
+            spec: $BUILDER -> $STRUCT,
+            fields: {
+                $(
+                    {
+                        req: $FIELD_REQ,
+                        lazy: $FIELD_LAZY,
+                        nested: [ $( $F_NESTED )* ],
+                        default: $FIELD_DEFAULT,
+                        spec: $F_NAME: $F_TY
+                    },
+                )*
+            }
+        );
+
+        impl_builder!(
+            @to_builder
+            variant: $VARIANT,
+            sources: $SOURCES,
+            spec: $BUILDER -> $STRUCT,
+            fields: [ $( { nested: [ $( $F_NESTED )* ], name: $F_NAME } ),* ]
+        );
+
+        impl_builder!(
+            @patch
+            patch: $PATCH,
+            purpose: $PURPOSE,
+            variant: $VARIANT,
+            build: $BUILD,
+            sources: $SOURCES,
+            spec: $BUILDER -> $STRUCT,
+            fields: [ $( {
+                no_setter: $F_NO_SETTER,
+                nested: [ $( $F_NESTED )* ],
+                spec: $F_NAME: $F_TY
+            } ),* ]
+        );
+
+        impl_builder!(
+            @validate
+            validate: $VALIDATE,
+            purpose: $PURPOSE,
+            variant: $VARIANT,
+            build: $BUILD,
+            setters: {
+                setter_prefix: $SETTER_PREFIX,
+                setter_suffix: $SETTER_SUFFIX,
+                fields: [ $( {
+                    req: $FIELD_REQ,
+                    no_setter: $F_NO_SETTER,
+                    setter: [ $( $F_SETTER )* ],
+                    name: $F_NAME
+                } )* ]
+            },
+            spec: $BUILDER -> $STRUCT,
+            field_groups: $FIELD_GROUPS,
+            sources: $SOURCES,
+            fields: {
+                $(
+                    {
+                        lazy: $FIELD_LAZY,
+                        nested: [ $( $F_NESTED )* ],
+                        default: $FIELD_DEFAULT,
+                        spec: $F_NAME: $F_TY
+                    },
+                )*
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        );
+
+        impl_builder!(
+            @setter_names
+            setter_prefix: $SETTER_PREFIX,
+            setter_suffix: $SETTER_SUFFIX,
+            fields: [ $( {
+                req: $FIELD_REQ,
+                no_setter: $F_NO_SETTER,
+                setter: [ $( $F_SETTER )* ],
+                name: $F_NAME
+            } )* ],
+            names: [],
+            then: {
+                @check_setters
+                dollar: ($),
+                sources: $SOURCES,
+                fields: [ $( $F_NAME )* ],
+                optional: [ $( { req: $FIELD_REQ, name: $F_NAME } )* ],
+                nested: [ $( {
+                    no_setter: $F_NO_SETTER,
+                    nested: [ $( $F_NESTED )* ],
+                    name: $F_NAME
+                } )* ],
+                generated: [],
+            }
+        );
+
+        impl_builder!(
+            @builder_trait
+            purpose: $PURPOSE,
+            variant: $VARIANT,
+            build: $BUILD,
+            spec: $BUILDER -> $STRUCT
+        );
     };
 }
@@ -3,7 +3,7 @@
 /// The purpose can be either `data` or `object`.
 ///
 /// The purpose should be `data` when the generated `build()` method should return
-/// `Result<Struct, &'static str>`. This should be used when the generated struct is to be
+/// `Result<Struct, BuildError>`. This should be used when the generated struct is to be
 /// constructed from input at runtime.
 ///
 /// The purpose should be `object` when the generated `build()` method should return
@@ -16,13 +16,30 @@ macro_rules! impl_struct_and_builder {
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$META:meta] )* ],
+        builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+        names: $NAMES:tt,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        patch: $PATCH:ident,
+        patch_meta: [ $( #[$PATCH_META:meta] )* ],
+        impl_default: $IMPL_DEFAULT:ident,
+        validate: $VALIDATE:ident,
         spec: $BUILDER:ident -> $STRUCT:ident,
         fields: {
             $(
                 {
                     req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
                     vis: [ $( $FIELD_VIS:ident )* ],
                     meta: [ $( #[$FIELD_META:meta] )* ],
+                    struct_meta: [ $( #[$FIELD_STRUCT_META:meta] )* ],
+                    builder_meta: [ $( #[$FIELD_BUILDER_META:meta] )* ],
+                    docs: [ $( #[doc = $FIELD_DOC:expr] )* ],
+                    setter: [ $( $FIELD_SETTER:ident )* ],
+                    no_setter: $FIELD_NO_SETTER:ident,
+                    getter: $FIELD_GETTER:ident,
+                    nested: [ $( $FIELD_NESTED:ident )* ],
+                    set_once: $FIELD_SET_ONCE:ident,
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
@@ -35,12 +52,20 @@ macro_rules! impl_struct_and_builder {
         declare_structs! {
             vis: [ $( $VIS )* ],
             meta: [ $( #[$META] )* ],
+            builder_meta: [ $( #[$BUILDER_META] )* ],
+            sources: $SOURCES,
+            patch: $PATCH,
+            patch_meta: [ $( #[$PATCH_META] )* ],
             spec: $BUILDER -> $STRUCT,
             fields: {
                 $(
                     {
                         vis: [ $( $FIELD_VIS )* ],
                         meta: [ $( #[$FIELD_META] )* ],
+                        struct_meta: [ $( #[$FIELD_STRUCT_META] )* ],
+                        builder_meta: [ $( #[$FIELD_BUILDER_META] )* ],
+                        no_setter: $FIELD_NO_SETTER,
+                        nested: [ $( $FIELD_NESTED )* ],
                         spec: $F_NAME: $F_TY
                     },
                 )*
@@ -50,11 +75,24 @@ macro_rules! impl_struct_and_builder {
         impl_builder! {
             purpose: $PURPOSE,
             variant: non_consuming,
+            names: $NAMES,
+            field_groups: $FIELD_GROUPS,
+            sources: $SOURCES,
+            patch: $PATCH,
+            impl_default: $IMPL_DEFAULT,
+            validate: $VALIDATE,
             spec: $BUILDER -> $STRUCT,
             fields: {
                 $(
                     {
                         req: $FIELD_REQ,
+                        lazy: $FIELD_LAZY,
+                        docs: [ $( #[doc = $FIELD_DOC] )* ],
+                        setter: [ $( $FIELD_SETTER )* ],
+                        no_setter: $FIELD_NO_SETTER,
+                        getter: $FIELD_GETTER,
+                        nested: [ $( $FIELD_NESTED )* ],
+                        set_once: $FIELD_SET_ONCE,
                         default: $FIELD_DEFAULT,
                         spec: $F_NAME: $F_TY
                     },
@@ -69,13 +107,30 @@ macro_rules! impl_struct_and_builder {
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$META:meta] )* ],
+        builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+        names: $NAMES:tt,
+        field_groups: $FIELD_GROUPS:tt,
+        sources: $SOURCES:tt,
+        patch: $PATCH:ident,
+        patch_meta: [ $( #[$PATCH_META:meta] )* ],
+        impl_default: $IMPL_DEFAULT:ident,
+        validate: $VALIDATE:ident,
         spec: $BUILDER:ident => $STRUCT:ident,
         fields: {
             $(
                 {
                     req: $FIELD_REQ:ident,
+                    lazy: $FIELD_LAZY:ident,
                     vis: [ $( $FIELD_VIS:ident )* ],
                     meta: [ $( #[$FIELD_META:meta] )* ],
+                    struct_meta: [ $( #[$FIELD_STRUCT_META:meta] )* ],
+                    builder_meta: [ $( #[$FIELD_BUILDER_META:meta] )* ],
+                    docs: [ $( #[doc = $FIELD_DOC:expr] )* ],
+                    setter: [ $( $FIELD_SETTER:ident )* ],
+                    no_setter: $FIELD_NO_SETTER:ident,
+                    getter: $FIELD_GETTER:ident,
+                    nested: [ $( $FIELD_NESTED:ident )* ],
+                    set_once: $FIELD_SET_ONCE:ident,
                     default: $FIELD_DEFAULT:expr,
                     spec: $F_NAME:ident: $F_TY:ty
                 },
@@ -88,12 +143,20 @@ macro_rules! impl_struct_and_builder {
         declare_structs! {
             vis: [ $( $VIS )* ],
             meta: [ $( #[$META] )* ],
+            builder_meta: [ $( #[$BUILDER_META] )* ],
+            sources: $SOURCES,
+            patch: $PATCH,
+            patch_meta: [ $( #[$PATCH_META] )* ],
             spec: $BUILDER => $STRUCT,
             fields: {
                 $(
                     {
                         vis: [ $( $FIELD_VIS )* ],
                         meta: [ $( #[$FIELD_META] )* ],
+                        struct_meta: [ $( #[$FIELD_STRUCT_META] )* ],
+                        builder_meta: [ $( #[$FIELD_BUILDER_META] )* ],
+                        no_setter: $FIELD_NO_SETTER,
+                        nested: [ $( $FIELD_NESTED )* ],
                         spec: $F_NAME: $F_TY
                     },
                 )*
@@ -103,11 +166,24 @@ macro_rules! impl_struct_and_builder {
         impl_builder! {
             purpose: $PURPOSE,
             variant: consuming,
+            names: $NAMES,
+            field_groups: $FIELD_GROUPS,
+            sources: $SOURCES,
+            patch: $PATCH,
+            impl_default: $IMPL_DEFAULT,
+            validate: $VALIDATE,
             spec: $BUILDER -> $STRUCT,
             fields: {
                 $(
                     {
                         req: $FIELD_REQ,
+                        lazy: $FIELD_LAZY,
+                        docs: [ $( #[doc = $FIELD_DOC] )* ],
+                        setter: [ $( $FIELD_SETTER )* ],
+                        no_setter: $FIELD_NO_SETTER,
+                        getter: $FIELD_GETTER,
+                        nested: [ $( $FIELD_NESTED )* ],
+                        set_once: $FIELD_SET_ONCE,
                         default: $FIELD_DEFAULT,
                         spec: $F_NAME: $F_TY
                     },
@@ -2,7 +2,7 @@
 
 //! This crate contains two macros to declare a struct and a corresponding builder.
 //!
-//! * `data_struct!`: The builder returns a `Result<StructName, &'static str>`
+//! * `data_struct!`: The builder returns a `Result<StructName, BuildError>`
 //! * `object_struct!`: The builder returns the declared `StructName`
 //!
 //! The macro is inspired from [jadpole/builder-macro][1], and is designed to remove duplication of
@@ -42,6 +42,15 @@
 //! # fn main() {} // necessary to allow doc test to pass
 //! ```
 //!
+//! ## Upgrading from 0.5
+//!
+//! `data_struct!` builders return a `Result<StructName, BuildError>` instead of a
+//! `Result<StructName, &'static str>`. Code that used the message can match on the `BuildError`
+//! instead, or call `to_string()` on it, which returns the same message for failed assertions.
+//!
+//! Defaults may only use the `?` operator when the field is annotated with `#[builder(lazy)]`, as
+//! other defaults are evaluated by the constructor, which cannot fail.
+//!
 //! # Examples
 //!
 //! _**Disclaimer:** The examples use the `data_struct!` macro. They are equally valid for the
@@ -72,46 +81,89 @@
 //! # }
 //! ```
 //!
+//! The builder may also be constructed through the struct, using `StructName::builder(...)`. It
+//! takes the same parameters as `BuilderName::new(...)`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! # data_struct!(ItemBuilder -> Item {
+//! #     required_field: i32,
+//! #     defaulted_field: &'static str = "abc",
+//! # });
+//! let item = Item::builder(123).defaulted_field("def").build().unwrap();
+//!
+//! assert_eq!(123, item.required_field);
+//! assert_eq!("def", item.defaulted_field);
+//! # }
+//! ```
+//!
 //! The generated code functions as follows:
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate builder_macro;
 //! #
+//! use builder_macro::FieldValue;
+//!
 //! # fn main() {
 //! struct Item {
 //!     required_field: i32,
 //!     defaulted_field: &'static str,
 //! }
 //!
-//! /// Auto-generated builder
+//! /// Builder for `Item`.
 //! struct ItemBuilder {
-//!     required_field: Option<i32>,
-//!     defaulted_field: Option<&'static str>,
+//!     required_field: FieldValue<i32>,
+//!     defaulted_field: FieldValue<&'static str>,
 //! }
 //!
 //! impl ItemBuilder {
-//!     /// Construct the builder
+//!     /// Constructs a builder for `Item`.
+//!     ///
+//!     /// # Parameters
+//!     ///
+//!     /// * `required_field`:
 //!     pub fn new(required_field: i32) -> ItemBuilder {
-//!         ItemBuilder { required_field: Some(required_field), defaulted_field: Some("abc"), }
+//!         ItemBuilder {
+//!             required_field: FieldValue::Set(required_field),
+//!             defaulted_field: FieldValue::Default("abc"),
+//!         }
 //!     }
 //!
 //!     /// Build the struct
-//!     pub fn build(&self) -> Result<Item, &'static str> {
-//!         let required_field = self.required_field.clone().ok_or(
-//!             concat!("Must pass argument for field: '", stringify!(required_field), "'"))?;
-//!         let defaulted_field = self.defaulted_field.clone().ok_or(
-//!             concat!("Must pass argument for field: '", stringify!(defaulted_field), "'"))?;
+//!     pub fn build(&self) -> Result<Item, builder_macro::BuildError> {
+//!         let required_field = self.required_field.clone().into_value().unwrap();
+//!         let defaulted_field = self.defaulted_field.clone().into_value().unwrap();
 //!
 //!         Ok(Item { required_field: required_field, defaulted_field: defaulted_field })
 //!     }
 //!
 //!     #[allow(dead_code)]
-//!     /// Auto-generated setter
+//!     /// Sets `defaulted_field`.
 //!     pub fn defaulted_field(&mut self, defaulted_field: &'static str) -> &mut Self {
-//!         self.defaulted_field = Some(defaulted_field);
+//!         self.defaulted_field = FieldValue::Set(defaulted_field);
 //!         self
 //!     }
+//!
+//!     // Accessors such as `get_defaulted_field()` are also generated, see "Inspecting Builders"
+//! }
+//!
+//! impl Item {
+//!     #[allow(dead_code)]
+//!     /// Returns a new `ItemBuilder` for this struct.
+//!     ///
+//!     /// # Parameters
+//!     ///
+//!     /// * `required_field`:
+//!     pub fn builder(required_field: i32) -> ItemBuilder {
+//!         ItemBuilder {
+//!             required_field: FieldValue::Set(required_field),
+//!             defaulted_field: FieldValue::Default("abc"),
+//!         }
+//!     }
 //! }
 //! # }
 //! ```
@@ -204,6 +256,486 @@
 //! # }
 //! ```
 //!
+//! ## Builder Name
+//!
+//! The builder name may be omitted, in which case it is the struct name followed by `Builder`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct!(-> Point {
+//!     x: i32,
+//!     y: i32 = 0,
+//! });
+//!
+//! mod inner {
+//!     data_struct!(pub => Line {
+//!         pub length: u32 = 1,
+//!     });
+//! }
+//!
+//! let point = PointBuilder::new(1).build().unwrap();
+//! let line = inner::LineBuilder::new().length(5).build().unwrap();
+//!
+//! assert_eq!((point.x, point.y), (1, 0));
+//! assert_eq!(line.length, 5);
+//! # }
+//! ```
+//!
+//! ## Builder Attributes
+//!
+//! Attributes on the struct are only copied onto the struct. To add attributes to the builder,
+//! such as derives or documentation, wrap them in `#[builder_attr(...)]`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[derive(Debug, PartialEq)]
+//!     #[builder_attr(derive(Clone, Debug), must_use)]
+//!     ItemBuilder -> Item {
+//!         name: &'static str = "item",
+//!     }
+//! }
+//!
+//! let builder = ItemBuilder::new();
+//! let item = builder.clone().build().unwrap();
+//!
+//! assert_eq!(format!("{:?}", builder), r#"ItemBuilder { name: Default("item") }"#);
+//! assert_eq!(item, Item { name: "item" });
+//! # }
+//! ```
+//!
+//! Attributes on fields are copied onto both the struct field and the builder field. To only copy
+//! an attribute onto one of them, wrap it in `#[struct_attr(...)]` or `#[builder_attr(...)]`:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     ItemBuilder -> Item {
+//!         #[struct_attr(doc = "Name of the item")]
+//!         #[builder_attr(doc = "Name of the item, if it has been set")]
+//!         #[allow(dead_code)]
+//!         name: &'static str = "item",
+//!     }
+//! }
+//!
+//! let item = ItemBuilder::new().build().unwrap();
+//! # let _ = item;
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! Doc comments on fields are copied onto the struct's fields, as well as the generated setters.
+//! Required fields are documented as parameters of `BuilderName::new(...)` and
+//! `StructName::builder(...)`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     /// A server to connect to.
+//!     pub ServerBuilder -> Server {
+//!         /// Host name of the server.
+//!         pub host: String,
+//!         /// Port that the server listens on.
+//!         ///
+//!         /// Defaults to 80.
+//!         pub port: u16 = 80,
+//!     }
+//! }
+//! # let server = ServerBuilder::new("localhost".to_string()).port(8080).build().unwrap();
+//! # assert_eq!(server.port, 8080);
+//! # }
+//! ```
+//!
+//! The generated builder is documented as "Builder for `Server`.", `new()` documents the `host`
+//! parameter as "Host name of the server.", and the `port()` setter is documented with the docs
+//! of the `port` field.
+//!
+//! ## Method Names
+//!
+//! The names of the constructor, build method and setters may be changed using the following
+//! attributes on the struct:
+//!
+//! * `#[builder(constructor = name)]`: Name of the constructor, defaults to `new`.
+//! * `#[builder(build = name)]`: Name of the build method, defaults to `build`.
+//! * `#[builder(setter_prefix = prefix)]`: Prefix for setter names, e.g. `with_`.
+//! * `#[builder(setter_suffix = suffix)]`: Suffix for setter names.
+//!
+//! A field's setter may be renamed with `#[builder(setter = name)]`, which ignores the prefix and
+//! suffix. A setter that is named like another method generated for the builder, such as
+//! `get_field_name` or `unset_field_name`, fails to compile.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(constructor = create)]
+//!     #[builder(build = finish)]
+//!     #[builder(setter_prefix = with_)]
+//!     RequestBuilder -> Request {
+//!         url: &'static str,
+//!         method: &'static str = "GET",
+//!         #[builder(setter = timeout_secs)]
+//!         timeout: u64 = 30,
+//!     }
+//! }
+//!
+//! let request = RequestBuilder::create("https://example.com")
+//!     .with_method("POST")
+//!     .timeout_secs(10)
+//!     .finish()
+//!     .unwrap();
+//!
+//! assert_eq!(request.method, "POST");
+//! assert_eq!(request.timeout, 10);
+//! # }
+//! ```
+//!
+//! ## Fields Without Setters
+//!
+//! Fields annotated with `#[builder(no_setter)]` do not have a setter, so they always take their
+//! default value when the struct is built from a new builder.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use std::collections::HashMap;
+//! #
+//! # fn main() {
+//! data_struct!(CacheBuilder -> Cache {
+//!     capacity: usize = 16,
+//!     #[builder(no_setter)]
+//!     entries: HashMap<String, String> = HashMap::new(),
+//! });
+//!
+//! // CacheBuilder::new().entries(..) does not compile
+//! let cache = CacheBuilder::new().capacity(32).build().unwrap();
+//!
+//! assert!(cache.entries.is_empty());
+//! # }
+//! ```
+//!
+//! ## Set-Once Fields
+//!
+//! Fields annotated with `#[builder(set_once)]` may only be set once. Setting them again is
+//! recorded, and `build()` returns a `BuildError::FieldOverwritten` naming the field, or panics
+//! for object builders. This catches layered configuration code that sets the same field twice by
+//! mistake, where the second value would otherwise silently win.
+//!
+//! The values of a struct that was converted back into a builder, such as with `to_builder()`, do
+//! not count as set, so each set-once field may still be changed once before building again.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct!(ConfigBuilder -> Config {
+//!     #[builder(set_once)]
+//!     port: u16 = 8080,
+//! });
+//!
+//! let config = ConfigBuilder::new().port(3000).build().unwrap();
+//! assert_eq!(config.port, 3000);
+//!
+//! let error = ConfigBuilder::new().port(3000).port(4000).build().err().unwrap();
+//! assert_eq!(error.to_string(), "field 'port' may only be set once");
+//!
+//! let config = config.to_builder().port(4000).build().unwrap();
+//! assert_eq!(config.port, 4000);
+//! # }
+//! ```
+//!
+//! ## Getters
+//!
+//! Private fields may be read from outside the module through getters on the struct, which keeps
+//! the struct immutable once it is built. Getters are generated for fields annotated with:
+//!
+//! * `#[builder(getter)]`: Returns a reference to the field.
+//! * `#[builder(getter = copy)]`: Returns a copy of the field, for `Copy` types.
+//! * `#[builder(getter = str)]`: Returns a `&str`, for `String` fields.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! mod inner {
+//!     data_struct!(pub UserBuilder -> User {
+//!         #[builder(getter = str)]
+//!         name: String,
+//!         #[builder(getter = copy)]
+//!         age: u32 = 0,
+//!         #[builder(getter)]
+//!         roles: Vec<&'static str> = vec!["member"],
+//!     });
+//! }
+//!
+//! let user = inner::UserBuilder::new("Ferris".to_string()).age(8).build().unwrap();
+//!
+//! assert_eq!(user.name(), "Ferris");
+//! assert_eq!(user.age(), 8);
+//! assert_eq!(user.roles(), &vec!["member"]);
+//! # }
+//! ```
+//!
+//! ## Inspecting Builders
+//!
+//! Builders have accessors to inspect their fields before the struct is built:
+//!
+//! * `get_field_name()` returns an `Option<&T>` of the field's current value, which is `None`
+//!   while a lazy default has not been evaluated.
+//! * `is_field_name_set()` returns whether the field has been set through the constructor or a
+//!   setter.
+//! * `unset_field_name()` returns a field that has a default value to its default.
+//! * `reset()` returns all fields that have a default value to their defaults. It is not generated
+//!   when a setter is named `reset`, after applying the setter prefix and suffix, as it would clash
+//!   with the setter.
+//!
+//! The builder stores each field as a `FieldValue`, which also records whether the field has been
+//! set. `FieldValue` is an implementation detail of the generated builders.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct!(ServerBuilder -> Server {
+//!     host: &'static str,
+//!     port: u16 = 80,
+//! });
+//!
+//! let mut builder = ServerBuilder::new("localhost");
+//! builder.port(443);
+//!
+//! assert_eq!(builder.get_port(), Some(&443));
+//! assert!(builder.is_port_set());
+//!
+//! builder.reset();
+//!
+//! assert_eq!(builder.get_host(), Some(&"localhost"));
+//! assert_eq!(builder.get_port(), Some(&80));
+//! assert!(!builder.is_port_set());
+//! # }
+//! ```
+//!
+//! ## Provenance
+//!
+//! Builders of structs annotated with `#[builder(provenance)]` record where the value of each
+//! field came from, which helps when configuration is layered from several sources:
+//!
+//! * `set_source("label")` sets the source that is recorded for the fields that are set next.
+//! * `field_name_provenance()` returns a [`Provenance`](enum.Provenance.html), which is
+//!   `Provenance::Default`, `Provenance::Built` for values of a struct that was converted back into
+//!   the builder, or `Provenance::Set(source)` with the source that was active when the field was
+//!   set.
+//!
+//! Errors returned by `build()` of `data_struct!`s are wrapped in a `BuildError::WithProvenance`,
+//! whose message lists where each field's value came from. `object_struct!`s panic with the same
+//! list appended to the message. The original panic is caught, and the panic hook is silenced on
+//! the building thread until then, so the failure is only reported once.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use builder_macro::Provenance;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(provenance)]
+//!     ServerBuilder -> Server {
+//!         host: &'static str = "localhost",
+//!         port: u16 = 8080,
+//!     }, assertions: {
+//!         assert!(port >= 1024);
+//!     }
+//! }
+//!
+//! let mut builder = ServerBuilder::new();
+//! builder.set_source("config.toml").port(80);
+//!
+//! assert_eq!(builder.host_provenance(), Provenance::Default);
+//! assert_eq!(builder.port_provenance(), Provenance::Set(Some("config.toml")));
+//!
+//! let error = builder.build().err().unwrap();
+//! assert!(error.to_string().ends_with("(host: default, port: set by 'config.toml')"));
+//! # }
+//! ```
+//!
+//! ## Merging Builders
+//!
+//! `merge(other)` copies every field that is set in `other` onto the builder, and leaves the
+//! fields that `other` left at their defaults untouched. This allows configuration layers to be
+//! built separately and then overlaid on each other. Required fields are not copied, as they are
+//! always passed to the constructor of `other`, so the builder keeps its own values for them.
+//!
+//! Merging respects `#[builder(set_once)]`, and copies the sources of builders with
+//! `#[builder(provenance)]`. `merge()` is not generated when a setter is named `merge`, as it
+//! would clash with the setter.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct!(ServerBuilder -> Server {
+//!     host: &'static str = "localhost",
+//!     port: u16 = 8080,
+//! });
+//!
+//! let mut file = ServerBuilder::new();
+//! file.host("example.com").port(80);
+//! let mut cli = ServerBuilder::new();
+//! cli.port(3000);
+//!
+//! let server = file.merge(cli).build().unwrap();
+//!
+//! assert_eq!(server.host, "example.com");
+//! assert_eq!(server.port, 3000);
+//! # }
+//! ```
+//!
+//! ## Field Groups
+//!
+//! Fields that are shared by several structs may be declared once with `field_group!`, together
+//! with assertions on them. The group is included in a struct with `..group_name,` in its fields,
+//! which adds the group's fields at that position and merges its assertions with the struct's.
+//!
+//! `field_group!` declares a macro named after the group, so the group must be declared before the
+//! structs that include it, and `#[macro_export]` may be added to use it in other modules.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! field_group! {
+//!     connection_fields {
+//!         pub timeout: u64 = 30,
+//!         pub retries: u32 = 3,
+//!     }, assertions: {
+//!         assert!(retries <= 10);
+//!     }
+//! }
+//!
+//! data_struct!(DatabaseBuilder -> Database {
+//!     pub url: &'static str,
+//!     ..connection_fields,
+//! });
+//!
+//! data_struct!(CacheBuilder -> Cache {
+//!     ..connection_fields,
+//!     pub capacity: usize = 16,
+//! });
+//!
+//! let database = DatabaseBuilder::new("localhost").timeout(10).build().unwrap();
+//! let cache = CacheBuilder::new().build().unwrap();
+//!
+//! assert_eq!(database.timeout, 10);
+//! assert_eq!(cache.retries, 3);
+//! assert!(CacheBuilder::new().retries(20).build().is_err());
+//! # }
+//! ```
+//!
+//! ## Mutually Exclusive Fields
+//!
+//! Attributes on the struct restrict which combinations of fields may be set on the builder:
+//!
+//! * `#[builder(exactly_one(a, b, ..))]`: Exactly one of the fields must be set.
+//! * `#[builder(at_most_one(a, b, ..))]`: No more than one of the fields may be set.
+//! * `#[builder(at_least_one(a, b, ..))]`: One or more of the fields must be set.
+//! * `#[builder(all_or_none(a, b, ..))]`: Either all of the fields or none of them must be set.
+//!
+//! A field counts as set when it was passed to the constructor or a setter, so defaults do not
+//! count. Neither do the values of a struct that was converted back into a builder, such as by
+//! `to_builder()`, `with_field_name()` or `validate()`, as the struct already followed the rules
+//! when it was built. A rule is then only checked again once one of its fields is set, and the
+//! converted values that differ from their field's default count as set from then on. This is why
+//! the fields of a rule must implement `PartialEq`.
+//!
+//! The rules are checked before the assertions, and `build()` returns a
+//! `BuildError::FieldGroup` naming the fields of the rule that was broken. Object builders panic
+//! with its message instead.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(exactly_one(password, key_file))]
+//!     LoginBuilder -> Login {
+//!         pub user: &'static str,
+//!         pub password: Option<&'static str> = None,
+//!         pub key_file: Option<&'static str> = None,
+//!     }
+//! }
+//!
+//! let login = LoginBuilder::new("admin").key_file(Some("id_rsa")).build().unwrap();
+//! assert_eq!(login.key_file, Some("id_rsa"));
+//!
+//! let error = LoginBuilder::new("admin").build().err().unwrap();
+//! assert_eq!(error.to_string(), "exactly one of the fields 'password', 'key_file' must be set");
+//!
+//! assert!(login.with_password(Some("secret")).is_err());
+//! # }
+//! ```
+//!
+//! ## Conditionally Required Fields
+//!
+//! A field may be required only when another field is true or is set, using these attributes on
+//! the struct:
+//!
+//! * `#[builder(required_if(field, flag))]`: `field` must be set when the `bool` field `flag` is
+//!   `true`.
+//! * `#[builder(required_with(field, other))]`: `field` must be set when `other` is set.
+//!
+//! `build()` returns a `BuildError::FieldRequired` naming both fields when the requirement is not
+//! met, and object builders panic with its message. Lazy defaults are not evaluated for the
+//! condition, so a `flag` with a lazy default only counts as `true` once it is set. As with
+//! mutually exclusive fields, the values of a struct that was converted back into a builder do not
+//! count as set for the condition, but they do count as set for the required field.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(required_if(tls_cert, use_tls))]
+//!     #[builder(required_with(proxy_port, proxy_host))]
+//!     ClientBuilder -> Client {
+//!         pub use_tls: bool = false,
+//!         pub tls_cert: Option<&'static str> = None,
+//!         pub proxy_host: Option<&'static str> = None,
+//!         pub proxy_port: u16 = 3128,
+//!     }
+//! }
+//!
+//! let client = ClientBuilder::new().use_tls(true).tls_cert(Some("cert.pem")).build().unwrap();
+//! assert_eq!(client.tls_cert, Some("cert.pem"));
+//!
+//! let error = ClientBuilder::new().proxy_host(Some("proxy")).build().err().unwrap();
+//! assert_eq!(error.to_string(), "field 'proxy_port' must be set when 'proxy_host' is set");
+//! # }
+//! ```
+//!
 //! ## Assertions
 //!
 //! You may specify assertions after field declarations inside an `assertions: { ... }` block.
@@ -233,32 +765,392 @@
 //! let result_2 = BuilderName::new("").build();
 //!
 //! assert!(result_1.is_ok());
-//! assert_eq!(result_2.err(),
-//!            Some("assertion failed: 'assert!(! a_private_field . is_empty (  ))'"));
+//! assert_eq!(result_2.err().unwrap().to_string(),
+//!            "assertion failed: 'assert!(! a_private_field . is_empty (  ))'");
 //! # }
 //! ```
 //!
-//! ## Full Usage Format
+//! ## Changing Built Structs
 //!
-//! The full macro usage format is:
+//! The struct has a `with_field_name(self, value)` method for each field with a setter, which
+//! returns the struct with the field changed. The assertions are checked again, so that they hold
+//! for the struct's whole lifetime. `data_struct!`s return a `Result`, and `object_struct!`s
+//! panic if an assertion fails.
 //!
 //! ```rust
 //! # #[macro_use]
 //! # extern crate builder_macro;
 //! #
 //! # fn main() {
-//! // We declare the builder insider a module simply to demonstrate scope
+//! data_struct! {
+//!     RangeBuilder -> Range {
+//!         start: u32 = 0,
+//!         end: u32 = 10,
+//!     }, assertions: {
+//!         assert!(start <= end);
+//!     }
+//! }
+//!
+//! let range = RangeBuilder::new().build().unwrap();
+//! let range = range.with_end(20).unwrap();
+//!
+//! assert_eq!(range.end, 20);
+//! assert!(range.with_start(30).is_err());
+//! # }
+//! ```
+//!
+//! ## Validation
+//!
+//! Non-consuming builders generate a `validate(&self)` method on the builder, which checks whether
+//! the struct can be built, and on the struct, which checks the assertions again. This is useful
+//! after `pub` fields have been changed, or the struct has been deserialized. For `data_struct!`s,
+//! `validate()` returns a `Result<(), BuildError>`, and for `object_struct!`s, it panics if an
+//! assertion fails.
+//!
+//! The builder's `validate()` builds the struct and then discards it, so it costs as much as
+//! `build()`: lazy defaults are evaluated and nested structs are built.
+//!
+//! Consuming builders only have `validate(&self)` when the struct is annotated with
+//! `#[builder(validate)]`, as the builder then builds the struct from clones of its fields, and the
+//! struct checks a clone of itself. The struct must implement `Clone`.
+//!
+//! The builder's `validate()` is not generated when a setter is named `validate`, as it would clash
+//! with the setter.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     RangeBuilder -> Range {
+//!         pub start: u32 = 0,
+//!         pub end: u32 = 10,
+//!     }, assertions: {
+//!         assert!(start <= end);
+//!     }
+//! }
+//!
+//! let mut builder = RangeBuilder::new();
+//! builder.start(20);
+//!
+//! assert!(builder.validate().is_err());
+//!
+//! let mut range = RangeBuilder::new().build().unwrap();
+//! range.start = 20;
+//!
+//! assert!(range.validate().is_err());
+//! # }
+//! ```
+//!
+//! ## Converting Structs Into Builders
+//!
+//! The builder implements `From<StructName>`, so an existing struct can be converted back into a
+//! builder to change some of its fields. The assertions are checked again when it is built.
+//!
+//! Non-consuming builders also generate a `to_builder(&self)` method on the struct, which clones
+//! the struct's fields into a new builder.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     ServerBuilder -> Server {
+//!         host: &'static str,
+//!         port: u16 = 80,
+//!     }, assertions: {
+//!         assert!(port != 0);
+//!     }
+//! }
+//!
+//! let server = ServerBuilder::new("localhost").build().unwrap();
+//! let https_server = server.to_builder().port(443).build().unwrap();
+//! let invalid_server = ServerBuilder::from(server).port(0).build();
+//!
+//! assert_eq!(https_server.host, "localhost");
+//! assert_eq!(https_server.port, 443);
+//! assert!(invalid_server.is_err());
+//! # }
+//! ```
+//!
+//! ## Patches
+//!
+//! Structs annotated with `#[builder(patch)]` have a companion `StructNamePatch`, where every field
+//! with a setter is a public `Option` that is `None` when the field should be left unchanged.
+//! Fields marked with `#[builder(no_setter)]` are not part of the patch and keep their value:
+//!
+//! * `apply(&mut self, patch)` changes the fields that are set in the patch, and checks the
+//!   assertions again. If they fail, `data_struct!`s return an error and are left unchanged, and
+//!   `object_struct!`s panic.
+//! * `diff(&self, &other)` returns the patch that changes the struct into `other`. The fields must
+//!   implement `Clone` and `PartialEq`.
+//!
+//! The patch only derives `Default`. To add attributes to it, such as other derives, wrap them in
+//! `#[patch_attr(...)]`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(patch)]
+//!     #[patch_attr(derive(Debug, PartialEq))]
+//!     UserBuilder -> User {
+//!         pub name: &'static str,
+//!         pub age: u32 = 0,
+//!     }, assertions: {
+//!         assert!(age < 150);
+//!     }
+//! }
+//!
+//! let mut user = UserBuilder::new("alice").build().unwrap();
+//! user.apply(UserPatch { age: Some(30), ..Default::default() }).unwrap();
+//! assert_eq!(user.age, 30);
+//! assert!(user.apply(UserPatch { age: Some(200), ..Default::default() }).is_err());
+//!
+//! let older = UserBuilder::new("alice").age(31).build().unwrap();
+//! assert_eq!(user.diff(&older), UserPatch { name: None, age: Some(31) });
+//! # }
+//! ```
+//!
+//! ## Default Trait Defaults
+//!
+//! Use `= default` to default a field to `<T as Default>::default()`. To do this for every field
+//! without a default value, annotate the struct with `#[builder(default)]`. Fields that should
+//! still be passed to the constructor can be marked with `#[builder(required)]`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use std::collections::HashMap;
+//! #
+//! # fn main() {
+//! data_struct!(ItemBuilder -> Item {
+//!     names: Vec<String> = default,
+//! });
+//!
+//! data_struct! {
+//!     #[builder(default)]
+//!     InventoryBuilder -> Inventory {
+//!         #[builder(required)]
+//!         owner: String,
+//!         items: HashMap<String, u32>,
+//!         capacity: usize = 10,
+//!     }
+//! }
+//!
+//! let item = ItemBuilder::new().build().unwrap();
+//! let inventory = InventoryBuilder::new("Ferris".to_string()).build().unwrap();
+//!
+//! assert!(item.names.is_empty());
+//! assert!(inventory.items.is_empty());
+//! assert_eq!(inventory.capacity, 10);
+//! # }
+//! ```
+//!
+//! When the struct is annotated with `#[builder(impl_default)]`, the builder and struct both
+//! implement `Default` using the field defaults, so none of the fields may be required. For
+//! `data_struct!`s, `StructName::default()` panics if the assertions fail. Without the annotation,
+//! the struct may derive `Default` instead.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(impl_default)]
+//!     PointBuilder -> Point {
+//!         x: i32 = 0,
+//!         y: i32 = 0,
+//!     }
+//! }
+//!
+//! let point = Point { x: 5, ..Default::default() };
+//! let another = PointBuilder::default().y(3).build().unwrap();
+//!
+//! assert_eq!((point.x, point.y), (5, 0));
+//! assert_eq!((another.x, another.y), (0, 3));
+//! # }
+//! ```
+//!
+//! ## Nested Builders
+//!
+//! Fields whose type is another struct with a non-consuming builder may be annotated with
+//! `#[builder(nested = InnerBuilder)]`. The builder then stores the field's builder instead of its
+//! value:
+//!
+//! * `field_name_mut(|inner_builder| ...)` changes the field's builder in place.
+//! * The setter still accepts a built struct, which is converted into its builder.
+//! * The field's struct is built as part of `build()`.
+//!
+//! Nested structs must be declared with the same macro as the outer struct, and nested fields
+//! cannot be lazy. Defaulting a nested field with `= default` requires its struct to implement
+//! `Default`, such as with `#[builder(impl_default)]`. Nested structs are built through the
+//! [`Builder`](trait.Builder.html) trait, so their build method may be renamed.
+//!
+//! For `data_struct!`s, errors from building a nested struct are returned as `BuildError::Nested`.
+//! `field_path()` returns the path to the field that failed for errors about a particular field,
+//! e.g. `"server.port"` when the lazy default of `port` fails. When an assertion of the nested
+//! struct fails, the path only names the nested field, e.g. `"server"`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! data_struct! {
+//!     #[builder(impl_default)]
+//!     ServerBuilder -> Server {
+//!         pub host: &'static str = "localhost",
+//!         pub port: u16 = 80,
+//!     }, assertions: {
+//!         assert!(port != 0);
+//!     }
+//! }
+//!
+//! data_struct!(ConfigBuilder -> Config {
+//!     #[builder(nested = ServerBuilder)]
+//!     pub server: Server = default,
+//! });
+//!
+//! let mut builder = ConfigBuilder::new();
+//! builder.server_mut(|server| {
+//!     server.port(8080);
+//! });
+//! let config = builder.build().unwrap();
+//!
+//! assert_eq!(config.server.port, 8080);
+//!
+//! builder.server_mut(|server| {
+//!     server.port(0);
+//! });
+//! let error = builder.build().err().unwrap();
+//!
+//! assert_eq!(error.field_path(), Some("server".to_string()));
+//! # }
+//! ```
+//!
+//! ## Lazy Defaults
+//!
+//! Default values are evaluated when the builder is constructed. When a default is expensive to
+//! compute, annotate the field with `#[builder(lazy)]` to only evaluate it in `build()` if the
+//! field has not been set.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use std::collections::HashMap;
+//! #
+//! # fn main() {
+//! data_struct!(CacheBuilder -> Cache {
+//!     #[builder(lazy)]
+//!     entries: HashMap<u32, String> = HashMap::with_capacity(10_000),
+//! });
+//!
+//! // HashMap::with_capacity(10_000) is never called
+//! let cache = CacheBuilder::new().entries(HashMap::new()).build().unwrap();
+//!
+//! assert_eq!(cache.entries.capacity(), 0);
+//! # }
+//! ```
+//!
+//! ## Fallible Defaults
+//!
+//! For `data_struct!`s, lazy defaults may use the `?` operator. If the default value expression
+//! returns an error, `build()` returns a `BuildError::DefaultFailed` with the name of the field.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use builder_macro::BuildError;
+//! # use std::env;
+//! # use std::path::PathBuf;
+//! #
+//! # fn main() {
+//! data_struct!(ConfigBuilder -> Config {
+//!     #[builder(lazy)]
+//!     home_dir: PathBuf = PathBuf::from(env::var("BUILDER_MACRO_UNSET_HOME")?),
+//! });
+//!
+//! match ConfigBuilder::new().build() {
+//!     Err(BuildError::DefaultFailed { field, .. }) => assert_eq!(field, "home_dir"),
+//!     _ => panic!("Expected the default for home_dir to fail"),
+//! }
+//! # }
+//! ```
+//!
+//! ## Generic Builders
+//!
+//! Every generated builder implements the `Builder` trait, so code can be generic over builders.
+//! The trait method is named `try_build()` so that it does not shadow the generated `build()`. It
+//! returns `BuildError` for `data_struct!`s, and `Infallible` for `object_struct!`s.
+//!
+//! Non-consuming builders also implement the trait for references, which builds the struct without
+//! consuming the builder. Structs generated by `data_struct!` also implement
+//! `TryFrom<StructNameBuilder>`.
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # use builder_macro::Builder;
+//! # use std::convert::TryFrom;
+//! #
+//! fn build_all<B: Builder>(builders: Vec<B>) -> Result<Vec<B::Output>, B::Error> {
+//!     builders.into_iter().map(Builder::try_build).collect()
+//! }
+//!
+//! # fn main() {
+//! data_struct!(PointBuilder => Point {
+//!     x: i32 = 0,
+//!     y: i32 = 0,
+//! });
+//!
+//! let points = build_all(vec![PointBuilder::new().x(1), PointBuilder::new().x(2)]).unwrap();
+//! let point = Point::try_from(PointBuilder::new().y(3)).unwrap();
+//!
+//! assert_eq!((points[0].x, points[1].x, point.y), (1, 2, 3));
+//! # }
+//! ```
+//!
+//! ## Full Usage Format
+//!
+//! The full macro usage format is:
+//!
+//! ```rust
+//! # #[macro_use]
+//! # extern crate builder_macro;
+//! #
+//! # fn main() {
+//! // We declare the builder insider a module simply to demonstrate scope
 //! mod inner {
 //!     data_struct! {
 //!         /// StructName is an example struct.
 //!         /// These docs are copied over to the generated struct.
+//!         #[builder_attr(doc = "Builder for StructName.", derive(Debug))]
 //!         pub BuilderName -> StructName {
-//!             // meta attributes are copied over to the struct's fields
+//!             // meta attributes are copied over to the struct's and builder's fields
 //!             #[allow(dead_code)]
+//!             #[struct_attr(doc = "Only on the struct's field")]
+//!             #[builder_attr(doc = "Only on the builder's field")]
 //!             a_private_field: &'static str,
 //!
 //!             /// a_field is an i32 which must be between 0 and 100 inclusive
 //!             pub a_field: i32 = 50,
+//!
+//!             // uses <Vec<i32> as Default>::default()
+//!             pub a_defaulted_field: Vec<i32> = default,
+//!
+//!             // lazy defaults are only evaluated in build() if the field is not set
+//!             #[builder(lazy)]
+//!             pub a_lazy_field: Vec<i32> = vec![1, 2, 3],
 //!         }, assertions: {
 //!             assert!(a_field >= 0);
 //!             assert!(a_field <= 100);
@@ -281,6 +1173,10 @@
 //! [3]: https://doc.rust-lang.org/style/ownership/builders.html#consuming-builders
 //!
 
+// Used to derive the builder name from the struct name
+#[doc(hidden)]
+pub extern crate paste;
+
 // Order is important
 #[macro_use]
 mod declare_structs;
@@ -290,13 +1186,28 @@ mod impl_builder;
 mod impl_struct_and_builder;
 #[macro_use]
 mod parse_struct;
+#[macro_use]
+mod field_group;
+
+mod build_error;
+mod builder;
+mod field_value;
+mod provenance;
+
+pub use build_error::{BuildError, FieldGroupKind, RequiredWhen};
+pub use builder::Builder;
+#[doc(hidden)]
+pub use field_value::FieldValue;
+pub use provenance::Provenance;
+#[doc(hidden)]
+pub use provenance::Sources;
 
 // We cannot put these macros into submodules because they cannot be re-exported. See discussion:
 // https://github.com/rust-lang/rust/issues/29638
 // https://github.com/rust-lang/rfcs/blob/master/text/0453-macro-reform.md
 
 #[macro_export]
-/// Macro to declare a struct and a corresponding builder that returns a `Result<T, &'static str>`.
+/// Macro to declare a struct and a corresponding builder that returns a `Result<T, BuildError>`.
 /// See [the module documentation](index.html) for more.
 macro_rules! data_struct {
     ( $( $SPEC:tt )* )
@@ -311,7 +1222,7 @@ macro_rules! data_struct {
 }
 
 #[macro_export]
-/// Macro to declare a struct and a corresponding builder that returns a `Result<T, &'static str>`.
+/// Macro to declare a struct and a corresponding builder that returns `T`.
 /// See [the module documentation](index.html) for more.
 macro_rules! object_struct {
     ( $( $SPEC:tt )* )
@@ -327,6 +1238,8 @@ macro_rules! object_struct {
 
 #[cfg(test)]
 mod test {
+    use Builder;
+
     // used in consuming builder tests
     trait Magic {
         fn abracadabra(&mut self) -> i32;
@@ -340,11 +1253,23 @@ mod test {
         }
     }
 
+    // used in lazy default tests
+    fn unevaluated_default<T>() -> T {
+        panic!("default value should not be evaluated")
+    }
+
+    // used in builder trait tests
+    fn build_generic<B: Builder>(builder: B) -> Result<B::Output, B::Error> {
+        builder.try_build()
+    }
+
     macro_rules! macro_tests {
         ( $SUITE:ident, [ $( $PUB:ident )* ] ) => {
             mod $SUITE {
                 mod data {
-                    use test::{Dust, Magic};
+                    use {BuildError, FieldGroupKind, Provenance, RequiredWhen};
+                    use std::convert::TryFrom;
+                    use test::{Dust, Magic, build_generic, unevaluated_default};
 
                     #[test]
                     fn generates_struct_with_defaults() {
@@ -533,291 +1458,1944 @@ mod test {
                     }
 
                     #[test]
-                    fn generated_build_method_uses_assertions() {
+                    fn generates_struct_with_lazy_defaults() {
                         data_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            #[allow(dead_code)]
-                            $($PUB)* field_i32: i32 = 123,
-                        },
-                        assertions: {
-                            assert!(field_i32 > 0);
+                            #[builder(lazy)]
+                            $($PUB)* field_vec: Vec<i32> = vec![123],
                         });
 
-                        let result = MyStructBuilder::new().field_i32(-1).build();
+                        let my_struct = MyStructBuilder::new().build().unwrap();
 
-                        match result {
-                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
-                            Err(msg) => assert_eq!(msg,
-                                                   "assertion failed: 'assert!(field_i32 > 0)'"),
-                        }
+                        assert_eq!(my_struct.field_vec, vec![123]);
                     }
 
                     #[test]
-                    fn generated_consuming_build_method_uses_assertions() {
+                    fn generated_builder_does_not_evaluate_overridden_lazy_defaults() {
                         data_struct!(MyStructBuilder => MyStruct {
-                            #[allow(dead_code)]
-                            $($PUB)* field_i32: i32 = 123,
-                        },
-                        assertions: {
-                            assert!(field_i32 == 99);
+                            /// docs
+                            #[builder(lazy)]
+                            $($PUB)* field_trait: Box<dyn Magic> = unevaluated_default(),
                         });
 
-                        let result = MyStructBuilder::new().build();
+                        let mut my_struct = MyStructBuilder::new()
+                            .field_trait(Box::new(Dust { value: 1 }))
+                            .build().unwrap();
 
-                        let expected = "assertion failed: 'assert!(field_i32 == 99)'";
-                        match result {
-                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
-                            Err(msg) => assert_eq!(msg, expected),
-                        }
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
                     }
 
                     #[test]
-                    fn generated_consuming_build_method_asserts_on_trait_fields() {
-                        data_struct!(MyStructBuilder => MyStruct {
-                            #[allow(dead_code)]
-                            $($PUB)* field_trait: Box<Magic> = Box::new(Dust { value: 1 }),
-                        },
-                        assertions: {
-                            assert_eq!(field_trait.abracadabra(), 99);
+                    fn generates_struct_with_default_trait_defaults() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_vec: Vec<i32> = default,
+                            #[builder(lazy)]
+                            $($PUB)* field_string: String = default,
                         });
 
-                        let result = MyStructBuilder::new().build();
+                        let my_struct = MyStructBuilder::new().build().unwrap();
 
-                        match result {
-                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
-                            Err(msg) => {
-                                assert_eq!(msg,
-                                           "assertion failed: 'assert_eq!(field_trait . \
-                                            abracadabra (  ) , 99)'")
-                            }
-                        }
+                        assert_eq!(my_struct.field_vec, Vec::<i32>::new());
+                        assert_eq!(my_struct.field_string, "");
                     }
 
-                    mod visibility_test {
-                        data_struct!(OuterStructBuilder -> OuterStruct { field_i32: i32 = 1, });
+                    #[test]
+                    fn generates_struct_with_default_trait_defaults_for_all_fields() {
+                        data_struct!(
+                            #[builder(default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(required)]
+                            $($PUB)* field_i32: i32,
+                            $($PUB)* field_vec: Vec<i32>,
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
 
-                        mod inner {
-                            data_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                                field_i32: i32 = 1,
-                            });
-                            data_struct!(pub InnerStructBuilder -> InnerStruct {
-                                pub field_i32: i32 = 1,
-                            });
+                        let my_struct = MyStructBuilder::new(123).build().unwrap();
 
-                            #[test]
-                            fn can_access_private_struct_from_within_module() {
-                                let my_struct = MyStructBuilder::new().build().unwrap();
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_vec, Vec::<i32>::new());
+                        assert_eq!(my_struct.field_str, "abc");
+                    }
 
-                                assert_eq!(my_struct.field_i32, 1);
+                    #[test]
+                    fn generates_default_impls_when_requested() {
+                        data_struct! {
+                            #[builder(impl_default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_vec: Vec<i32> = vec![456],
                             }
+                        }
 
-                            #[test]
-                            fn can_access_private_outer_struct_from_inner_module() {
-                                let outer_struct = super::OuterStructBuilder::new().build()
-                                    .unwrap();
+                        let my_struct = MyStruct { field_i32: 1, ..Default::default() };
+                        let my_struct_2 = MyStructBuilder::default().field_i32(2).build().unwrap();
 
-                                assert_eq!(outer_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_vec, vec![456]);
+                        assert_eq!(my_struct_2.field_i32, 2);
+                        assert_eq!(my_struct_2.field_vec, vec![456]);
+                    }
+
+                    #[test]
+                    fn generated_struct_may_derive_default() {
+                        data_struct! {
+                            #[derive(Default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
                             }
                         }
 
-                        #[test]
-                        fn can_access_public_struct_from_outside_module() {
-                            let inner_struct = inner::InnerStructBuilder::new().build().unwrap();
+                        let my_struct = MyStruct::default();
+                        let my_struct_2 = MyStructBuilder::new().build().unwrap();
 
-                            assert_eq!(inner_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_i32, 0);
+                        assert_eq!(my_struct_2.field_i32, 123);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_default_impl_panics_when_assertions_fail() {
+                        data_struct! {
+                            #[builder(impl_default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                #[allow(dead_code)]
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 == 99);
+                            }
                         }
 
-                        // The following causes a compilation failure if uncommented
-                        // #[test]
-                        // fn cannot_access_private_struct() {
-                        //     let my_struct = inner::MyStructBuilder::new().build().unwrap();
-                        //
-                        //     assert_eq!(my_struct.field_i32, 0);
-                        // }
+                        MyStruct::default();
                     }
-                }
-
-                mod object {
-                    use test::{Dust, Magic};
 
                     #[test]
-                    fn generates_struct_with_defaults() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            $($PUB)* field_i32: i32 = 123,
-                            $($PUB)* field_str: &'static str = "abc",
+                    fn generated_builder_can_be_created_from_struct() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_str: &'static str,
+                            $($PUB)* field_i32: i32 = 1,
+                        },
+                        assertions: {
+                            assert!(field_i32 > 0);
                         });
 
-                        let my_struct = MyStructBuilder::new().build();
+                        let my_struct = MyStructBuilder::new("abc").build().unwrap();
+                        let my_struct_2 = MyStructBuilder::from(my_struct).field_i32(2)
+                            .build().unwrap();
+                        let result = my_struct_2.to_builder().field_i32(-1).build();
 
-                        assert_eq!(my_struct.field_i32, 123);
-                        assert_eq!(my_struct.field_str, "abc");
+                        assert_eq!(my_struct_2.field_str, "abc");
+                        assert_eq!(my_struct_2.field_i32, 2);
+                        assert!(result.is_err());
                     }
 
                     #[test]
-                    fn generates_struct_with_no_defaults_and_parameters() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            $($PUB)* field_i32: i32,
-                            $($PUB)* field_str: &'static str,
+                    fn generated_builder_implements_builder_trait() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                        },
+                        assertions: {
+                            assert!(field_i32 > 0);
                         });
 
-                        let my_struct = MyStructBuilder::new(456, "str").build();
+                        let my_struct = build_generic(MyStructBuilder::new()).unwrap();
+                        let mut builder = MyStructBuilder::new();
+                        builder.field_i32(-1);
+                        let result = MyStruct::try_from(builder);
 
-                        assert_eq!(my_struct.field_i32, 456);
-                        assert_eq!(my_struct.field_str, "str");
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert!(result.is_err());
                     }
 
                     #[test]
-                    fn generates_struct_with_mixed_defaults_and_parameters() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            /// docs
+                    fn generated_struct_constructs_builder() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
                             $($PUB)* field_i32: i32,
-                            /// docs
                             $($PUB)* field_str: &'static str = "abc",
                         });
 
-                        let my_struct = MyStructBuilder::new(456).build();
+                        let my_struct = MyStruct::builder(123).build().unwrap();
 
-                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_i32, 123);
                         assert_eq!(my_struct.field_str, "abc");
                     }
 
                     #[test]
-                    fn generates_struct_with_mixed_defaults_and_specified_parameters() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            /// docs
+                    fn generates_builder_name_when_omitted() {
+                        data_struct!($($PUB)* -> MyStruct {
                             $($PUB)* field_i32: i32,
-                            /// docs
                             $($PUB)* field_str: &'static str = "abc",
+                        },
+                        assertions: {
+                            assert!(field_i32 > 0);
+                        });
+                        data_struct!(#[derive(Debug)] $($PUB)* => MyOtherStruct {
+                            $($PUB)* field_i32: i32 = 456,
                         });
 
-                        let my_struct = MyStructBuilder::new(456).field_str("str").build();
+                        let my_struct = MyStructBuilder::new(123).field_str("def").build().unwrap();
+                        let result = MyStructBuilder::new(-1).build();
+                        let my_other_struct = MyOtherStructBuilder::new().build().unwrap();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_str, "def");
+                        assert!(result.is_err());
+                        assert_eq!(my_other_struct.field_i32, 456);
+                    }
 
+                    #[test]
+                    fn generates_builder_with_builder_attributes() {
+                        data_struct! {
+                            #[builder_attr(derive(Clone, Debug), must_use)]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let builder = MyStructBuilder::new().field_i32(456);
+                        let debug = format!("{:?}", builder);
+                        let my_struct = builder.clone().build().unwrap();
+
+                        assert_eq!(debug, "MyStructBuilder { field_i32: Set(456) }");
                         assert_eq!(my_struct.field_i32, 456);
-                        assert_eq!(my_struct.field_str, "str");
                     }
 
                     #[test]
-                    fn generates_struct_with_mixed_defaults_maintains_order() {
-                        object_struct!(
+                    fn generates_struct_with_struct_and_builder_field_attributes() {
+                        data_struct! {
                             #[derive(Debug)]
-                            MyStructBuilder -> MyStruct {
-                            /// docs
-                            $($PUB)* field_a: i32,
-                            /// docs
-                            $($PUB)* field_b: &'static str = "abc",
-                            /// docs
-                            $($PUB)* field_c: i32 = 456,
-                            /// docs
-                            $($PUB)* field_d: &'static str,
-                        });
+                            #[builder_attr(derive(Debug))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                #[struct_attr(allow(dead_code))]
+                                #[builder_attr(allow(unused))]
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
 
-                        let my_struct = MyStructBuilder::new(123, "def").build();
+                        let builder = MyStructBuilder::new();
+                        let my_struct = builder.build().unwrap();
 
-                        assert_eq!(my_struct.field_a, 123);
-                        assert_eq!(my_struct.field_b, "abc");
-                        assert_eq!(my_struct.field_c, 456);
-                        assert_eq!(my_struct.field_d, "def");
+                        assert_eq!(
+                            format!("{:?}", builder),
+                            "MyStructBuilder { field_i32: Default(123) }"
+                        );
+                        assert_eq!(format!("{:?}", my_struct), "MyStruct { field_i32: 123 }");
+                    }
 
-                        assert_eq!("MyStruct { field_a: 123, field_b: \"abc\", field_c: 456, \
-                                    field_d: \"def\" }",
-                                   format!("{:?}", my_struct));
+                    #[test]
+                    fn generates_struct_with_field_docs() {
+                        data_struct! {
+                            /// Struct docs
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                /// Required field docs
+                                $($PUB)* field_i32: i32,
+                                /// Optional field docs
+                                ///
+                                /// Spanning multiple lines
+                                #[allow(dead_code)]
+                                $($PUB)* field_str: &'static str = "abc",
+                            }
+                        }
+
+                        let my_struct = MyStruct::builder(123).field_str("def").build().unwrap();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_str, "def");
                     }
 
                     #[test]
-                    fn generates_struct_with_defaults_and_parameters() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                    fn generates_builder_with_custom_method_names() {
+                        data_struct! {
+                            #[builder(constructor = create)]
+                            #[builder(build = finish)]
+                            #[builder(setter_prefix = with_)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32,
+                                $($PUB)* field_str: &'static str = "abc",
+                                #[builder(setter = vec)]
+                                $($PUB)* field_vec: Vec<i32> = vec![],
+                            }
+                        }
+                        data_struct! {
+                            #[builder(build = try_build)]
+                            #[builder(setter_suffix = _value)]
+                            #[builder(impl_default)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::create(1)
+                            .with_field_str("def")
+                            .vec(vec![2])
+                            .finish()
+                            .unwrap();
+                        let my_other_struct =
+                            MyOtherStructBuilder::new().field_i32_value(456).try_build().unwrap();
+                        let generic_struct = build_generic(MyOtherStructBuilder::new()).unwrap();
+
+                        assert_eq!(my_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_str, "def");
+                        assert_eq!(my_struct.field_vec, vec![2]);
+                        assert_eq!(my_other_struct.field_i32, 456);
+                        assert_eq!(generic_struct.field_i32, 123);
+                        assert_eq!(MyOtherStruct::default().field_i32, 123);
+                    }
+
+                    #[test]
+                    fn generates_builder_without_setters_for_no_setter_fields() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
                             $($PUB)* field_i32: i32 = 123,
-                            $($PUB)* field_str: &'static str = "abc",
+                            #[builder(no_setter)]
+                            $($PUB)* field_vec: Vec<i32> = vec![1, 2],
                         });
 
-                        let my_struct = MyStructBuilder::new()
-                            .field_i32(456)
-                            .field_str("str")
-                            .build();
+                        let my_struct = MyStructBuilder::new().field_i32(456).build().unwrap();
 
                         assert_eq!(my_struct.field_i32, 456);
-                        assert_eq!(my_struct.field_str, "str");
+                        assert_eq!(my_struct.field_vec, vec![1, 2]);
                     }
 
                     #[test]
-                    fn generates_struct_with_generic_types() {
-                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
-                            $($PUB)* field_vec: Vec<i32> = vec![123],
+                    fn generates_builder_accessors() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_str: &'static str,
+                            $($PUB)* field_i32: i32 = 123,
+                            #[builder(lazy)]
+                            $($PUB)* field_vec: Vec<i32> = vec![1, 2],
                         });
 
-                        let my_struct = MyStructBuilder::new().build();
+                        let mut builder = MyStructBuilder::new("abc");
+                        builder.field_i32(456).field_vec(vec![3]);
 
-                        let my_struct_2 = MyStructBuilder::new()
-                            .field_vec(vec![234, 456])
-                            .build();
+                        assert_eq!(builder.get_field_str(), Some(&"abc"));
+                        assert_eq!(builder.get_field_i32(), Some(&456));
+                        assert_eq!(builder.get_field_vec(), Some(&vec![3]));
+                        assert!(builder.is_field_str_set());
+                        assert!(builder.is_field_i32_set());
 
+                        builder.unset_field_i32();
 
-                        assert_eq!(my_struct.field_vec, vec![123]);
-                        assert_eq!(my_struct_2.field_vec, vec![234, 456]);
+                        assert_eq!(builder.get_field_i32(), Some(&123));
+                        assert!(!builder.is_field_i32_set());
+
+                        builder.field_i32(789).reset();
+
+                        assert_eq!(builder.get_field_str(), Some(&"abc"));
+                        assert_eq!(builder.get_field_i32(), Some(&123));
+                        assert_eq!(builder.get_field_vec(), None);
+                        assert!(!builder.is_field_vec_set());
+
+                        let my_struct = builder.build().unwrap();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_vec, vec![1, 2]);
                     }
 
                     #[test]
-                    fn generates_struct_with_traits_using_default_values() {
-                        // Note: we use => instead of -> for the consuming variant of the builder
-                        object_struct!(MyStructBuilder => MyStruct {
-                            $($PUB)* field_trait: Box<Magic> = Box::new(Dust { value: 1 }),
-                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                    fn generates_builder_without_methods_named_like_setters() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* reset: bool = false,
+                            $($PUB)* validate: bool = false,
+                            $($PUB)* merge: bool = false,
+                        });
+                        data_struct!($($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                            #[builder(setter = reset)]
+                            $($PUB)* field_i32: i32 = 123,
                         });
 
-                        let mut my_struct = MyStructBuilder::new().build();
+                        data_struct! {
+                            #[builder(setter_prefix = with_)]
+                            $($PUB)* MyThirdStructBuilder -> MyThirdStruct {
+                                $($PUB)* reset: bool,
+                                $($PUB)* merge: bool = false,
+                            }
+                        }
 
+                        let mut builder = MyStructBuilder::new();
+                        builder.reset(true).validate(true).merge(true);
+                        let my_struct = builder.build().unwrap();
+                        let my_other_struct =
+                            MyOtherStructBuilder::new().reset(456).build().unwrap();
 
-                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
-                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                        assert!(my_struct.reset);
+                        assert!(my_struct.validate);
+                        assert!(my_struct.merge);
+                        assert!(my_struct.validate().is_ok());
+                        assert_eq!(my_other_struct.field_i32, 456);
+
+                        let mut builder = MyThirdStructBuilder::new(true);
+                        builder.with_merge(true).reset().merge(MyThirdStructBuilder::new(false));
+                        let my_third_struct = builder.build().unwrap();
+
+                        assert!(my_third_struct.reset);
+                        assert!(!my_third_struct.merge);
                     }
 
                     #[test]
-                    fn generates_struct_with_traits_specifying_parameters() {
-                        // Note: we use => instead of -> for the consuming variant of the builder
-                        object_struct!(MyStructBuilder => MyStruct {
-                            $($PUB)* field_trait: Box<Magic>,
-                            $($PUB)* field_vec: Vec<Box<Magic>>,
+                    fn generates_struct_getters() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(getter)]
+                            field_vec: Vec<i32> = vec![1, 2],
+                            #[builder(getter = copy)]
+                            field_i32: i32 = 123,
+                            #[builder(getter = str)]
+                            field_string: String = "abc".to_string(),
                         });
 
-                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }),
-                                                                 vec![Box::new(Dust { value: 2 })])
-                            .build();
+                        let my_struct = MyStructBuilder::new().field_i32(456).build().unwrap();
 
+                        assert_eq!(my_struct.field_vec(), &vec![1, 2]);
+                        assert_eq!(my_struct.field_i32(), 456);
+                        assert_eq!(my_struct.field_string(), "abc");
+                    }
 
-                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
-                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                    #[test]
+                    fn generates_struct_withers_that_check_assertions() {
+                        data_struct! {
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::new().build().unwrap();
+                        let my_struct = my_struct
+                            .with_field_i32(456)
+                            .unwrap()
+                            .with_field_str("def")
+                            .unwrap();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "def");
+                        assert!(my_struct.with_field_i32(0).is_err());
                     }
 
                     #[test]
-                    fn generates_struct_with_traits_and_mixed_defaults_and_parameters() {
-                        object_struct!(MyStructBuilder => MyStruct {
-                            /// docs
-                            $($PUB)* field_trait: Box<Magic>,
-                            /// docs
-                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                    fn generates_validate_methods() {
+                        data_struct! {
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let mut builder = MyStructBuilder::new();
+
+                        assert!(builder.validate().is_ok());
+                        assert!(builder.field_i32(0).validate().is_err());
+
+                        let mut my_struct = MyStructBuilder::new().build().unwrap();
+
+                        assert!(my_struct.validate().is_ok());
+
+                        my_struct.field_i32 = -1;
+
+                        match my_struct.validate() {
+                            Err(BuildError::AssertionFailed(_)) => {}
+                            _ => panic!("Expected BuildError::AssertionFailed"),
+                        }
+                    }
+
+                    #[test]
+                    fn generates_validate_methods_for_consuming_builders_that_opt_in() {
+                        data_struct! {
+                            #[derive(Clone)]
+                            #[builder(validate)]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let builder = MyStructBuilder::new().field_i32(0);
+
+                        assert!(builder.validate().is_err());
+
+                        let builder = builder.field_i32(1);
+
+                        assert!(builder.validate().is_ok());
+
+                        let mut my_struct = builder.build().unwrap();
+
+                        assert!(my_struct.validate().is_ok());
+
+                        my_struct.field_i32 = -1;
+
+                        assert!(my_struct.validate().is_err());
+                    }
+
+                    #[test]
+                    fn generates_builder_with_nested_builders() {
+                        data_struct! {
+                            #[builder(impl_default)]
+                            #[builder(build = finish)]
+                            $($PUB)* InnerBuilder -> Inner {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+                        data_struct! {
+                            #[builder(impl_default)]
+                            $($PUB)* MiddleBuilder -> Middle {
+                                #[builder(nested = InnerBuilder)]
+                                $($PUB)* field_inner: Inner = default,
+                            }
+                        }
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(nested = MiddleBuilder)]
+                            $($PUB)* field_middle: Middle = default,
                         });
 
-                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }))
-                            .build();
+                        let mut builder = MyStructBuilder::new();
 
-                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
-                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                        assert!(!builder.is_field_middle_set());
+
+                        builder.field_middle_mut(|middle| {
+                            middle.field_inner_mut(|inner| {
+                                inner.field_i32(456);
+                            });
+                        });
+                        let my_struct = builder.build().unwrap();
+
+                        assert!(builder.is_field_middle_set());
+                        assert_eq!(my_struct.field_middle.field_inner.field_i32, 456);
+
+                        builder.field_middle_mut(|middle| {
+                            middle.field_inner_mut(|inner| {
+                                inner.field_i32(0);
+                            });
+                        });
+                        let error = builder.build().err().unwrap();
+
+                        assert_eq!(
+                            error.field_path(),
+                            Some("field_middle.field_inner".to_string())
+                        );
+
+                        let inner_builder = InnerBuilder::new();
+
+                        assert_eq!(build_generic(&inner_builder).unwrap().field_i32, 123);
+
+                        let inner = InnerBuilder::new().field_i32(789).finish().unwrap();
+                        let middle = MiddleBuilder::new().field_inner(inner).build().unwrap();
+                        let my_struct = my_struct.with_field_middle(middle).unwrap();
+
+                        assert_eq!(my_struct.field_middle.field_inner.field_i32, 789);
                     }
 
                     #[test]
-                    fn generates_struct_with_traits_and_mixed_defaults_and_specified_parameters() {
-                        object_struct!(MyStructBuilder => MyStruct {
-                            /// docs
-                            $($PUB)* field_trait: Box<Magic>,
-                            /// docs
-                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                    fn generates_structs_with_field_groups() {
+                        field_group! {
+                            common_fields {
+                                $($PUB)* field_i32: i32 = 123,
+                                #[builder(lazy)]
+                                $($PUB)* field_vec: Vec<i32> = vec![1, 2],
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+                        field_group!(no_assertion_fields {
+                            $($PUB)* field_str: &'static str = "abc",
                         });
 
-                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }))
-                            .field_vec(vec![Box::new(Dust { value: 3 })])
-                            .build();
+                        data_struct! {
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_string: String,
+                                ..common_fields,
+                                ..no_assertion_fields,
+                            }, assertions: {
+                                assert!(!field_string.is_empty());
+                            }
+                        }
+                        data_struct!($($PUB)* MyOtherStructBuilder -> MyOtherStruct {
+                            ..common_fields,
+                        });
 
-                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
-                        assert_eq!(my_struct.field_vec[0].abracadabra(), 3);
+                        let my_struct =
+                            MyStructBuilder::new("def".to_string()).field_i32(456).build().unwrap();
+                        let my_other_struct = MyOtherStructBuilder::new().build().unwrap();
+
+                        assert_eq!(my_struct.field_string, "def");
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_vec, vec![1, 2]);
+                        assert_eq!(my_struct.field_str, "abc");
+                        assert_eq!(my_other_struct.field_i32, 123);
+                        assert!(
+                            MyStructBuilder::new("def".to_string()).field_i32(0).build().is_err()
+                        );
+                        assert!(MyStructBuilder::new(String::new()).build().is_err());
+                        assert!(MyOtherStructBuilder::new().field_i32(0).build().is_err());
+                    }
+
+                    #[test]
+                    fn generated_build_method_checks_field_set_rules() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(exactly_one(field_password, field_key_file))]
+                            #[builder(at_most_one(field_i32, field_i64))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_password: Option<&'static str> = None,
+                                $($PUB)* field_key_file: Option<&'static str> = None,
+                                $($PUB)* field_i32: i32 = 1,
+                                $($PUB)* field_i64: i64 = 2,
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(at_least_one(field_i32, field_i64))]
+                            #[builder(all_or_none(field_host, field_port))]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                $($PUB)* field_i32: i32 = 1,
+                                $($PUB)* field_i64: i64 = 2,
+                                $($PUB)* field_host: &'static str = "localhost",
+                                $($PUB)* field_port: u16 = 80,
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::new()
+                            .field_password(Some("abc"))
+                            .field_i64(3)
+                            .build()
+                            .unwrap();
+                        let my_other_struct = MyOtherStructBuilder::new()
+                            .field_i32(3)
+                            .field_host("example.com")
+                            .field_port(8080)
+                            .build()
+                            .unwrap();
+
+                        assert_eq!(my_struct.field_password, Some("abc"));
+                        assert_eq!(my_struct.field_i64, 3);
+                        assert_eq!(my_other_struct.field_i32, 3);
+                        assert_eq!(my_other_struct.field_port, 8080);
+                        assert!(MyOtherStructBuilder::new().field_i64(3).build().is_ok());
+
+                        match MyStructBuilder::new().build() {
+                            Err(BuildError::FieldGroup { kind, fields }) => {
+                                assert_eq!(kind, FieldGroupKind::ExactlyOne);
+                                assert_eq!(fields, &["field_password", "field_key_file"]);
+                            }
+                            _ => panic!("expected a field group error"),
+                        }
+                        let error = MyStructBuilder::new()
+                            .field_password(None)
+                            .field_key_file(None)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "exactly one of the fields 'field_password', \
+                             'field_key_file' must be set"
+                        );
+                        let error = MyStructBuilder::new()
+                            .field_key_file(Some("key.pem"))
+                            .field_i32(3)
+                            .field_i64(4)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "at most one of the fields 'field_i32', 'field_i64' must be set"
+                        );
+                        let error = MyOtherStructBuilder::new().build().unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "at least one of the fields 'field_i32', 'field_i64' must be set"
+                        );
+                        let error = MyOtherStructBuilder::new()
+                            .field_i32(3)
+                            .field_port(8080)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "all or none of the fields 'field_host', 'field_port' must be set"
+                        );
+                        assert_eq!(error.field_path(), None);
+                    }
+
+                    #[test]
+                    fn generated_struct_passes_field_set_rules_after_conversion() {
+                        data_struct! {
+                            #[derive(Clone, Debug)]
+                            #[builder(exactly_one(field_password, field_key_file))]
+                            #[builder(patch)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_user: &'static str = "admin",
+                                $($PUB)* field_password: Option<&'static str> = None,
+                                $($PUB)* field_key_file: Option<&'static str> = None,
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new()
+                            .field_password(Some("abc"))
+                            .build()
+                            .unwrap();
+
+                        assert!(my_struct.validate().is_ok());
+                        assert!(my_struct.to_builder().build().is_ok());
+                        assert!(!my_struct.to_builder().is_field_password_set());
+                        assert!(my_struct.to_builder().field_password(None).build().is_ok());
+                        assert!(MyStructBuilder::from(my_struct.clone()).build().is_ok());
+
+                        my_struct.apply(MyStructPatch {
+                            field_user: Some("root"),
+                            ..Default::default()
+                        }).unwrap();
+                        let my_struct = my_struct.with_field_user("guest").unwrap();
+
+                        assert_eq!(my_struct.field_user, "guest");
+                        assert_eq!(my_struct.field_password, Some("abc"));
+                        assert!(my_struct.to_builder().field_password(Some("def")).build().is_ok());
+
+                        match my_struct.clone().with_field_key_file(Some("id_rsa")) {
+                            Err(BuildError::FieldGroup { kind, .. }) => {
+                                assert_eq!(kind, FieldGroupKind::ExactlyOne)
+                            }
+                            _ => panic!("expected a field group error"),
+                        }
+                        assert!(my_struct
+                            .to_builder()
+                            .field_key_file(Some("id_rsa"))
+                            .build()
+                            .is_err());
+
+                        let error = my_struct
+                            .to_builder()
+                            .field_password(None)
+                            .field_key_file(None)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "exactly one of the fields 'field_password', \
+                             'field_key_file' must be set"
+                        );
+                    }
+
+                    #[test]
+                    fn generated_build_method_checks_conditional_requirements() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(required_if(field_cert, field_tls))]
+                            #[builder(required_with(field_port, field_host))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_tls: bool = false,
+                                $($PUB)* field_cert: Option<&'static str> = None,
+                                $($PUB)* field_host: &'static str = "localhost",
+                                $($PUB)* field_port: u16 = 80,
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::new()
+                            .field_tls(true)
+                            .field_cert(Some("cert.pem"))
+                            .field_host("example.com")
+                            .field_port(443)
+                            .build()
+                            .unwrap();
+
+                        assert_eq!(my_struct.field_cert, Some("cert.pem"));
+                        assert_eq!(my_struct.field_port, 443);
+                        assert!(
+                            MyStructBuilder::new().field_tls(false).field_port(8080).build().is_ok()
+                        );
+
+                        match MyStructBuilder::new().field_tls(true).build() {
+                            Err(BuildError::FieldRequired { field, required_by, when }) => {
+                                assert_eq!(field, "field_cert");
+                                assert_eq!(required_by, "field_tls");
+                                assert_eq!(when, RequiredWhen::True);
+                            }
+                            _ => panic!("expected a field required error"),
+                        }
+                        let error = MyStructBuilder::new().field_tls(true).build().unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "field 'field_cert' must be set when 'field_tls' is true"
+                        );
+                        assert_eq!(error.field_path(), Some("field_cert".to_string()));
+                        let error =
+                            MyStructBuilder::new().field_host("example.com").build().unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "field 'field_port' must be set when 'field_host' is set"
+                        );
+                    }
+
+                    #[test]
+                    fn generated_struct_keeps_conditional_requirements_after_conversion() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(required_if(field_cert, field_tls))]
+                            #[builder(required_with(field_port, field_host))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_tls: bool = false,
+                                $($PUB)* field_cert: Option<&'static str> = None,
+                                $($PUB)* field_host: &'static str = "localhost",
+                                $($PUB)* field_port: u16 = 80,
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::new()
+                            .field_cert(Some("cert.pem"))
+                            .field_host("example.com")
+                            .field_port(443)
+                            .build()
+                            .unwrap();
+
+                        assert!(my_struct.to_builder().field_tls(true).build().is_ok());
+
+                        let my_struct = my_struct.with_field_host("example.org").unwrap();
+
+                        assert_eq!(my_struct.field_host, "example.org");
+                        assert_eq!(my_struct.field_port, 443);
+                    }
+
+                    #[test]
+                    fn generated_build_method_rejects_overwritten_set_once_fields() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let mut builder = MyStructBuilder::new();
+                        builder.field_i32(456).field_str("def").field_str("ghi");
+                        let my_struct = builder.build().unwrap();
+                        builder.field_i32(789);
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "ghi");
+                        assert!(builder.is_field_i32_set());
+                        assert_eq!(builder.get_field_i32(), Some(&789));
+                        match builder.build() {
+                            Err(BuildError::FieldOverwritten { field }) => {
+                                assert_eq!(field, "field_i32")
+                            }
+                            _ => panic!("expected a field overwritten error"),
+                        }
+                        assert!(builder.reset().field_i32(1).build().is_ok());
+
+                        let error = MyOtherStructBuilder::new()
+                            .field_i32(456)
+                            .field_i32(789)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(error.to_string(), "field 'field_i32' may only be set once");
+                        assert_eq!(error.field_path(), Some("field_i32".to_string()));
+                    }
+
+                    #[test]
+                    fn generated_builder_from_struct_allows_setting_set_once_fields() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let my_struct = MyStructBuilder::new().field_i32(1).build().unwrap();
+                        let my_struct_2 = my_struct.to_builder().field_i32(2).build().unwrap();
+                        let my_other_struct = MyOtherStructBuilder::new().field_i32(1).build();
+
+                        assert_eq!(my_struct_2.field_i32, 2);
+                        assert!(my_struct.to_builder().field_i32(2).field_i32(3).build().is_err());
+                        assert_eq!(my_struct.with_field_i32(2).unwrap().field_i32, 2);
+                        let my_other_struct = MyOtherStructBuilder::from(my_other_struct.unwrap())
+                            .field_i32(2)
+                            .build()
+                            .unwrap();
+
+                        assert_eq!(my_other_struct.field_i32, 2);
+                    }
+
+                    #[test]
+                    fn generated_builder_tracks_provenance() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(provenance)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_str: &'static str,
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_i64: i64 = 456,
+                                #[builder(lazy)]
+                                $($PUB)* field_vec: Vec<i32> = vec![1],
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(provenance)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let mut builder = MyStructBuilder::new("abc");
+                        builder.set_source("file").field_i32(1).field_i64(2);
+                        builder.set_source("env").field_i32(3).field_vec(vec![4]);
+
+                        assert_eq!(builder.field_str_provenance(), Provenance::Set(None));
+                        assert_eq!(builder.field_i32_provenance(), Provenance::Set(Some("env")));
+                        assert_eq!(builder.field_i64_provenance(), Provenance::Set(Some("file")));
+                        assert_eq!(builder.field_vec_provenance(), Provenance::Set(Some("env")));
+                        assert_eq!(
+                            builder.unset_field_i64().field_i64_provenance(),
+                            Provenance::Default
+                        );
+                        assert_eq!(builder.build().unwrap().field_i32, 3);
+
+                        match builder.field_i32(-1).build() {
+                            Err(BuildError::WithProvenance { error, fields }) => {
+                                match *error {
+                                    BuildError::AssertionFailed(_) => {}
+                                    _ => panic!("expected an assertion error"),
+                                }
+                                assert_eq!(fields[1], ("field_i32", Provenance::Set(Some("env"))));
+                                assert_eq!(fields[2], ("field_i64", Provenance::Default));
+                            }
+                            _ => panic!("expected an error with provenance"),
+                        }
+
+                        let error = MyOtherStructBuilder::new()
+                            .set_source("cli")
+                            .field_i32(1)
+                            .field_i32(2)
+                            .build()
+                            .unwrap_err();
+                        assert_eq!(
+                            error.to_string(),
+                            "field 'field_i32' may only be set once (field_i32: set by 'cli')"
+                        );
+                        assert_eq!(error.field_path(), Some("field_i32".to_string()));
+                        assert_eq!(
+                            MyOtherStructBuilder::new().field_i32(1).field_i32_provenance(),
+                            Provenance::Set(None)
+                        );
+                    }
+
+                    #[test]
+                    fn generated_builder_merges_set_fields() {
+                        data_struct! {
+                            #[builder(provenance)]
+                            #[builder_attr(derive(Clone))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                                #[builder(set_once)]
+                                $($PUB)* field_i64: i64 = 456,
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                            }
+                        }
+
+                        let mut builder = MyStructBuilder::new();
+                        builder.set_source("file").field_i32(1).field_str("def");
+                        let mut other = MyStructBuilder::new();
+                        other.set_source("env").field_str("ghi");
+                        let my_struct = builder.merge(other).build().unwrap();
+
+                        assert_eq!(my_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_str, "ghi");
+                        assert_eq!(my_struct.field_i64, 456);
+                        assert_eq!(builder.field_i32_provenance(), Provenance::Set(Some("file")));
+                        assert_eq!(builder.field_str_provenance(), Provenance::Set(Some("env")));
+                        assert_eq!(builder.field_i64_provenance(), Provenance::Default);
+
+                        let mut other = MyStructBuilder::new();
+                        other.field_i64(1);
+                        builder.merge(other.clone());
+                        assert!(builder.build().is_ok());
+                        builder.merge(other);
+                        match builder.build() {
+                            Err(BuildError::WithProvenance { error, .. }) => match *error {
+                                BuildError::FieldOverwritten { field } => {
+                                    assert_eq!(field, "field_i64")
+                                }
+                                _ => panic!("expected a field overwritten error"),
+                            },
+                            _ => panic!("expected an error with provenance"),
+                        }
+
+                        let my_other_struct = MyOtherStructBuilder::new()
+                            .field_i32(1)
+                            .merge(MyOtherStructBuilder::new().field_str("def"))
+                            .build()
+                            .unwrap();
+
+                        assert_eq!(my_other_struct.field_i32, 1);
+                        assert_eq!(my_other_struct.field_str, "def");
+                    }
+
+                    #[test]
+                    fn generated_builder_merge_keeps_required_fields() {
+                        data_struct! {
+                            #[derive(Debug)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                #[builder(set_once)]
+                                $($PUB)* field_str: &'static str,
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let mut overlay = MyStructBuilder::new("");
+                        overlay.field_i32(456);
+                        let my_struct = MyStructBuilder::new("abc").merge(overlay).build().unwrap();
+
+                        assert_eq!(my_struct.field_str, "abc");
+                        assert_eq!(my_struct.field_i32, 456);
+                    }
+
+                    #[test]
+                    fn generated_struct_applies_and_diffs_patches() {
+                        data_struct! {
+                            #[derive(Clone, Debug, PartialEq)]
+                            #[builder(patch)]
+                            #[patch_attr(derive(Debug, PartialEq))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                                $($PUB)* field_vec: Vec<i32> = vec![],
+                                #[builder(no_setter)]
+                                $($PUB)* field_version: u32 = 1,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+                        data_struct! {
+                            #[derive(Debug)]
+                            #[builder(patch)]
+                            $($PUB)* MyOtherStructBuilder => MyOtherStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new().build().unwrap();
+                        let patch = MyStructPatch { field_str: Some("def"), ..Default::default() };
+                        my_struct.apply(patch).unwrap();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_str, "def");
+
+                        let other =
+                            MyStructBuilder::new().field_i32(456).field_str("def").build().unwrap();
+                        let patch = my_struct.diff(&other);
+
+                        assert_eq!(
+                            patch,
+                            MyStructPatch { field_i32: Some(456), ..Default::default() }
+                        );
+                        my_struct.apply(patch).unwrap();
+                        assert_eq!(my_struct, other);
+                        assert_eq!(my_struct.diff(&other), MyStructPatch::default());
+
+                        let patch = MyStructPatch {
+                            field_i32: Some(-1),
+                            field_vec: Some(vec![1]),
+                            ..Default::default()
+                        };
+                        match my_struct.apply(patch) {
+                            Err(BuildError::AssertionFailed(_)) => {}
+                            _ => panic!("expected an assertion error"),
+                        }
+                        assert_eq!(my_struct, other);
+
+                        my_struct.field_version = 2;
+                        my_struct.apply(MyStructPatch::default()).unwrap();
+
+                        assert_eq!(my_struct.field_version, 2);
+                        assert_eq!(my_struct.diff(&other), MyStructPatch::default());
+
+                        let mut my_other_struct = MyOtherStructBuilder::new().build().unwrap();
+                        my_other_struct.apply(MyOtherStructPatch { field_i32: Some(456) }).unwrap();
+
+                        assert_eq!(my_other_struct.field_i32, 456);
+                    }
+
+                    #[test]
+                    fn generated_build_method_evaluates_fallible_lazy_defaults() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(lazy)]
+                            $($PUB)* field_i32: i32 = "123".parse::<i32>()?,
+                        });
+
+                        let my_struct = MyStructBuilder::new().build().unwrap();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                    }
+
+                    #[test]
+                    fn generated_build_method_returns_err_when_lazy_default_fails() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[allow(dead_code)]
+                            #[builder(lazy)]
+                            $($PUB)* field_i32: i32 = "abc".parse::<i32>()?,
+                        });
+
+                        let result = MyStructBuilder::new().build();
+
+                        match result {
+                            Ok(_) => panic!("Expected Err() caused by default failure"),
+                            Err(error) => {
+                                assert_eq!(error.to_string(),
+                                           "failed to evaluate default for field: 'field_i32': \
+                                            invalid digit found in string");
+                                match error {
+                                    BuildError::DefaultFailed { field, .. } => {
+                                        assert_eq!(field, "field_i32")
+                                    }
+                                    _ => panic!("Expected BuildError::DefaultFailed"),
+                                }
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn generated_build_method_uses_assertions() {
+                        data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[allow(dead_code)]
+                            $($PUB)* field_i32: i32 = 123,
+                        },
+                        assertions: {
+                            assert!(field_i32 > 0);
+                        });
+
+                        let result = MyStructBuilder::new().field_i32(-1).build();
+
+                        match result {
+                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
+                            Err(msg) => assert_eq!(msg.to_string(),
+                                                   "assertion failed: 'assert!(field_i32 > 0)'"),
+                        }
+                    }
+
+                    #[test]
+                    fn generated_consuming_build_method_uses_assertions() {
+                        data_struct!(MyStructBuilder => MyStruct {
+                            #[allow(dead_code)]
+                            $($PUB)* field_i32: i32 = 123,
+                        },
+                        assertions: {
+                            assert!(field_i32 == 99);
+                        });
+
+                        let result = MyStructBuilder::new().build();
+
+                        let expected = "assertion failed: 'assert!(field_i32 == 99)'";
+                        match result {
+                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
+                            Err(msg) => assert_eq!(msg.to_string(), expected),
+                        }
+                    }
+
+                    #[test]
+                    fn generated_consuming_build_method_asserts_on_trait_fields() {
+                        data_struct!(MyStructBuilder => MyStruct {
+                            #[allow(dead_code)]
+                            $($PUB)* field_trait: Box<Magic> = Box::new(Dust { value: 1 }),
+                        },
+                        assertions: {
+                            assert_eq!(field_trait.abracadabra(), 99);
+                        });
+
+                        let result = MyStructBuilder::new().build();
+
+                        match result {
+                            Ok(_) => panic!("Expected Err() caused by assertion failure"),
+                            Err(msg) => {
+                                assert_eq!(msg.to_string(),
+                                           "assertion failed: 'assert_eq!(field_trait . \
+                                            abracadabra (  ) , 99)'")
+                            }
+                        }
+                    }
+
+                    mod visibility_test {
+                        data_struct!(OuterStructBuilder -> OuterStruct { field_i32: i32 = 1, });
+
+                        mod inner {
+                            data_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                                field_i32: i32 = 1,
+                            });
+                            data_struct!(pub InnerStructBuilder -> InnerStruct {
+                                pub field_i32: i32 = 1,
+                            });
+
+                            #[test]
+                            fn can_access_private_struct_from_within_module() {
+                                let my_struct = MyStructBuilder::new().build().unwrap();
+
+                                assert_eq!(my_struct.field_i32, 1);
+                            }
+
+                            #[test]
+                            fn can_access_private_outer_struct_from_inner_module() {
+                                let outer_struct = super::OuterStructBuilder::new().build()
+                                    .unwrap();
+
+                                assert_eq!(outer_struct.field_i32, 1);
+                            }
+                        }
+
+                        #[test]
+                        fn can_access_public_struct_from_outside_module() {
+                            let inner_struct = inner::InnerStructBuilder::new().build().unwrap();
+
+                            assert_eq!(inner_struct.field_i32, 1);
+                        }
+
+                        // The following causes a compilation failure if uncommented
+                        // #[test]
+                        // fn cannot_access_private_struct() {
+                        //     let my_struct = inner::MyStructBuilder::new().build().unwrap();
+                        //
+                        //     assert_eq!(my_struct.field_i32, 0);
+                        // }
+                    }
+                }
+
+                mod object {
+                    use Provenance;
+                    use test::{Dust, Magic, build_generic, unevaluated_default};
+
+                    #[test]
+                    fn generates_struct_with_defaults() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
+
+                        let my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_str, "abc");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_no_defaults_and_parameters() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_i32: i32,
+                            $($PUB)* field_str: &'static str,
+                        });
+
+                        let my_struct = MyStructBuilder::new(456, "str").build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "str");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_mixed_defaults_and_parameters() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            /// docs
+                            $($PUB)* field_i32: i32,
+                            /// docs
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
+
+                        let my_struct = MyStructBuilder::new(456).build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "abc");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_mixed_defaults_and_specified_parameters() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            /// docs
+                            $($PUB)* field_i32: i32,
+                            /// docs
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
+
+                        let my_struct = MyStructBuilder::new(456).field_str("str").build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "str");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_mixed_defaults_maintains_order() {
+                        object_struct!(
+                            #[derive(Debug)]
+                            MyStructBuilder -> MyStruct {
+                            /// docs
+                            $($PUB)* field_a: i32,
+                            /// docs
+                            $($PUB)* field_b: &'static str = "abc",
+                            /// docs
+                            $($PUB)* field_c: i32 = 456,
+                            /// docs
+                            $($PUB)* field_d: &'static str,
+                        });
+
+                        let my_struct = MyStructBuilder::new(123, "def").build();
+
+                        assert_eq!(my_struct.field_a, 123);
+                        assert_eq!(my_struct.field_b, "abc");
+                        assert_eq!(my_struct.field_c, 456);
+                        assert_eq!(my_struct.field_d, "def");
+
+                        assert_eq!("MyStruct { field_a: 123, field_b: \"abc\", field_c: 456, \
+                                    field_d: \"def\" }",
+                                   format!("{:?}", my_struct));
+                    }
+
+                    #[test]
+                    fn generates_struct_with_defaults_and_parameters() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
+
+                        let my_struct = MyStructBuilder::new()
+                            .field_i32(456)
+                            .field_str("str")
+                            .build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "str");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_generic_types() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_vec: Vec<i32> = vec![123],
+                        });
+
+                        let my_struct = MyStructBuilder::new().build();
+
+                        let my_struct_2 = MyStructBuilder::new()
+                            .field_vec(vec![234, 456])
+                            .build();
+
+
+                        assert_eq!(my_struct.field_vec, vec![123]);
+                        assert_eq!(my_struct_2.field_vec, vec![234, 456]);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_traits_using_default_values() {
+                        // Note: we use => instead of -> for the consuming variant of the builder
+                        object_struct!(MyStructBuilder => MyStruct {
+                            $($PUB)* field_trait: Box<Magic> = Box::new(Dust { value: 1 }),
+                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                        });
+
+                        let mut my_struct = MyStructBuilder::new().build();
+
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_traits_specifying_parameters() {
+                        // Note: we use => instead of -> for the consuming variant of the builder
+                        object_struct!(MyStructBuilder => MyStruct {
+                            $($PUB)* field_trait: Box<Magic>,
+                            $($PUB)* field_vec: Vec<Box<Magic>>,
+                        });
+
+                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }),
+                                                                 vec![Box::new(Dust { value: 2 })])
+                            .build();
+
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_traits_and_mixed_defaults_and_parameters() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            /// docs
+                            $($PUB)* field_trait: Box<Magic>,
+                            /// docs
+                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                        });
+
+                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }))
+                            .build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(my_struct.field_vec[0].abracadabra(), 2);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_traits_and_mixed_defaults_and_specified_parameters() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            /// docs
+                            $($PUB)* field_trait: Box<Magic>,
+                            /// docs
+                            $($PUB)* field_vec: Vec<Box<Magic>> = vec![Box::new(Dust { value: 2 })],
+                        });
+
+                        let mut my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 }))
+                            .field_vec(vec![Box::new(Dust { value: 3 })])
+                            .build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(my_struct.field_vec[0].abracadabra(), 3);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_lazy_defaults() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(lazy)]
+                            $($PUB)* field_vec: Vec<i32> = vec![123],
+                        });
+
+                        let my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_vec, vec![123]);
+                    }
+
+                    #[test]
+                    fn generated_builder_does_not_evaluate_overridden_lazy_defaults() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            /// docs
+                            #[builder(lazy)]
+                            $($PUB)* field_trait: Box<dyn Magic> = unevaluated_default(),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new()
+                            .field_trait(Box::new(Dust { value: 1 }))
+                            .build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_default_trait_defaults() {
+                        object_struct!($($PUB)* MyStructBuilder -> MyStruct {
+                            $($PUB)* field_vec: Vec<i32> = default,
+                            #[builder(lazy)]
+                            $($PUB)* field_string: String = default,
+                        });
+
+                        let my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_vec, Vec::<i32>::new());
+                        assert_eq!(my_struct.field_string, "");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_default_trait_defaults_for_all_fields() {
+                        object_struct!(
+                            #[builder(default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                            #[builder(required)]
+                            $($PUB)* field_i32: i32,
+                            $($PUB)* field_vec: Vec<i32>,
+                            $($PUB)* field_str: &'static str = "abc",
+                        });
+
+                        let my_struct = MyStructBuilder::new(123).build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_vec, Vec::<i32>::new());
+                        assert_eq!(my_struct.field_str, "abc");
+                    }
+
+                    #[test]
+                    fn generates_default_impls_when_requested() {
+                        object_struct! {
+                            #[builder(impl_default)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_vec: Vec<i32> = vec![456],
+                            }
+                        }
+
+                        let my_struct = MyStruct { field_i32: 1, ..Default::default() };
+                        let my_struct_2 = MyStructBuilder::default().field_i32(2).build();
+
+                        assert_eq!(my_struct.field_i32, 1);
+                        assert_eq!(my_struct.field_vec, vec![456]);
+                        assert_eq!(my_struct_2.field_i32, 2);
+                        assert_eq!(my_struct_2.field_vec, vec![456]);
+                    }
+
+                    #[test]
+                    fn generated_builder_can_be_created_from_struct() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            $($PUB)* field_trait: Box<dyn Magic>,
+                            $($PUB)* field_i32: i32 = 123,
+                        });
+
+                        let my_struct = MyStructBuilder::new(Box::new(Dust { value: 1 })).build();
+                        let mut my_struct_2 = MyStructBuilder::from(my_struct).field_i32(456)
+                            .build();
+
+                        assert_eq!(my_struct_2.field_trait.abracadabra(), 1);
+                        assert_eq!(my_struct_2.field_i32, 456);
+                    }
+
+                    #[test]
+                    fn generated_builder_implements_builder_trait() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = build_generic(MyStructBuilder::new()).unwrap();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generated_struct_constructs_builder() {
+                        object_struct!(MyStructBuilder => MyStruct {
+                            $($PUB)* field_i32: i32,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStruct::builder(123).build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_builder_name_when_omitted() {
+                        object_struct!($($PUB)* => MyStruct {
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+                        object_struct!($($PUB)* -> MyOtherStruct {
+                            $($PUB)* field_i32: i32,
+                        });
+
+                        let mut my_struct = MyStructBuilder::new().build();
+                        let my_other_struct = MyOtherStructBuilder::new(123).build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(my_other_struct.field_i32, 123);
+                    }
+
+                    #[test]
+                    fn generates_builder_with_builder_attributes() {
+                        object_struct! {
+                            #[builder_attr(derive(Debug))]
+                            #[derive(Debug)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+
+                        let builder = MyStructBuilder::new();
+
+                        assert_eq!(
+                            format!("{:?}", builder),
+                            "MyStructBuilder { field_i32: Default(123) }"
+                        );
+                        assert_eq!(format!("{:?}", builder.build()), "MyStruct { field_i32: 123 }");
+                    }
+
+                    #[test]
+                    fn generates_struct_with_struct_and_builder_field_attributes() {
+                        object_struct! {
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                #[struct_attr(allow(dead_code), allow(unused))]
+                                #[builder_attr(allow(unused))]
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_struct_with_field_docs() {
+                        object_struct! {
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                /// Required field docs
+                                $($PUB)* field_i32: i32,
+                                #[doc = "Trait field docs"]
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new(123).build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_builder_with_custom_method_names() {
+                        object_struct! {
+                            #[builder(constructor = create)]
+                            #[builder(build = try_build)]
+                            #[builder(setter_prefix = with_)]
+                            #[builder(impl_default)]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                #[builder(setter = magic)]
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::create()
+                            .magic(Box::new(Dust { value: 2 }))
+                            .try_build();
+                        let mut generic_struct = build_generic(MyStructBuilder::create()).unwrap();
+                        let mut default_struct = MyStruct::default();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 2);
+                        assert_eq!(generic_struct.field_trait.abracadabra(), 1);
+                        assert_eq!(default_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_builder_without_setters_for_no_setter_fields() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            #[builder(no_setter)]
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_builder_accessors() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let builder = MyStructBuilder::new()
+                            .field_i32(456)
+                            .field_trait(Box::new(Dust { value: 2 }));
+
+                        assert_eq!(builder.get_field_i32(), Some(&456));
+                        assert!(builder.is_field_i32_set());
+                        assert!(builder.is_field_trait_set());
+
+                        let builder = builder.unset_field_i32();
+
+                        assert_eq!(builder.get_field_i32(), Some(&123));
+                        assert!(!builder.is_field_i32_set());
+                        assert!(builder.is_field_trait_set());
+
+                        let mut my_struct = builder.reset().build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    fn generates_struct_getters() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            #[builder(getter = copy)]
+                            field_i32: i32 = 123,
+                            #[builder(getter)]
+                            field_vec: Vec<i32> = vec![1, 2],
+                            #[allow(dead_code)]
+                            field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let my_struct = MyStructBuilder::new().field_i32(456).build();
+
+                        assert_eq!(my_struct.field_i32(), 456);
+                        assert_eq!(my_struct.field_vec(), &vec![1, 2]);
+                    }
+
+                    #[test]
+                    fn generates_struct_withers() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new()
+                            .build()
+                            .with_field_i32(456)
+                            .with_field_trait(Box::new(Dust { value: 2 }));
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 2);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_struct_withers_use_assertions() {
+                        object_struct! {
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        MyStructBuilder::new().build().with_field_i32(0);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_validate_method_uses_assertions() {
+                        object_struct! {
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new().build();
+                        my_struct.validate();
+
+                        my_struct.field_i32 = 0;
+                        my_struct.validate();
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_consuming_validate_method_uses_assertions() {
+                        object_struct! {
+                            #[derive(Clone)]
+                            #[builder(validate)]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let builder = MyStructBuilder::new();
+                        builder.validate();
+
+                        let my_struct = builder.build();
+                        my_struct.validate();
+
+                        MyStructBuilder::new().field_i32(0).validate();
+                    }
+
+                    #[test]
+                    fn generates_builder_with_nested_builders() {
+                        object_struct! {
+                            #[builder(impl_default)]
+                            #[builder(build = finish)]
+                            $($PUB)* InnerBuilder -> Inner {
+                                $($PUB)* field_i32: i32 = 123,
+                            }
+                        }
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            #[builder(nested = InnerBuilder)]
+                            $($PUB)* field_inner: Inner = default,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new()
+                            .field_inner_mut(|inner| {
+                                inner.field_i32(456);
+                            })
+                            .build();
+
+                        assert_eq!(my_struct.field_inner.field_i32, 456);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_build_method_uses_field_group_assertions() {
+                        field_group! {
+                            common_fields {
+                                $($PUB)* field_i32: i32 = 123,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            ..common_fields,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new().build();
+
+                        assert_eq!(my_struct.field_i32, 123);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+
+                        MyStructBuilder::new().field_i32(0).build();
+                    }
+
+                    #[test]
+                    #[should_panic(
+                        expected = "exactly one of the fields 'field_i32', 'field_i64' must be set"
+                    )]
+                    fn generated_build_method_checks_field_set_rules() {
+                        object_struct! {
+                            #[builder(exactly_one(field_i32, field_i64))]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_i32: i32 = 1,
+                                $($PUB)* field_i64: i64 = 2,
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new().field_i64(3).build();
+
+                        assert_eq!(my_struct.field_i64, 3);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+
+                        MyStructBuilder::new().build();
+                    }
+
+                    #[test]
+                    #[should_panic(
+                        expected = "field 'field_cert' must be set when 'field_tls' is true"
+                    )]
+                    fn generated_build_method_checks_conditional_requirements() {
+                        object_struct! {
+                            #[builder(required_if(field_cert, field_tls))]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_tls: bool = false,
+                                $($PUB)* field_cert: Option<&'static str> = None,
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new()
+                            .field_tls(true)
+                            .field_cert(Some("cert.pem"))
+                            .build();
+
+                        assert_eq!(my_struct.field_cert, Some("cert.pem"));
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+
+                        MyStructBuilder::new().field_tls(true).build();
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "field 'field_i32' may only be set once")]
+                    fn generated_build_method_rejects_overwritten_set_once_fields() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            #[builder(set_once)]
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let mut my_struct = MyStructBuilder::new().field_i32(456).build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+
+                        MyStructBuilder::new().field_i32(456).field_i32(789).build();
+                    }
+
+                    #[test]
+                    fn generated_builder_tracks_provenance() {
+                        object_struct! {
+                            #[builder(provenance)]
+                            $($PUB)* MyStructBuilder => MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                            }
+                        }
+
+                        let builder = MyStructBuilder::new().set_source("env").field_i32(456);
+
+                        assert_eq!(builder.field_i32_provenance(), Provenance::Set(Some("env")));
+                        assert_eq!(builder.field_trait_provenance(), Provenance::Default);
+
+                        let mut my_struct = builder.build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_trait.abracadabra(), 1);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "(field_i32: set by 'env', field_i64: default)")]
+                    fn generated_builder_panics_with_provenance() {
+                        object_struct! {
+                            #[builder(provenance)]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_i64: i64 = 456,
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        MyStructBuilder::new().set_source("env").field_i32(0).build();
+                    }
+
+                    #[test]
+                    fn generated_builder_merges_set_fields() {
+                        object_struct!($($PUB)* MyStructBuilder => MyStruct {
+                            $($PUB)* field_i32: i32 = 123,
+                            $($PUB)* field_str: &'static str = "abc",
+                            $($PUB)* field_trait: Box<dyn Magic> = Box::new(Dust { value: 1 }),
+                        });
+
+                        let other = MyStructBuilder::new().field_trait(Box::new(Dust { value: 2 }));
+                        let mut my_struct =
+                            MyStructBuilder::new().field_i32(456).merge(other).build();
+
+                        assert_eq!(my_struct.field_i32, 456);
+                        assert_eq!(my_struct.field_str, "abc");
+                        assert_eq!(my_struct.field_trait.abracadabra(), 2);
+                    }
+
+                    #[test]
+                    #[should_panic(expected = "assertion failed")]
+                    fn generated_struct_applies_patches_with_assertions() {
+                        object_struct! {
+                            #[builder(patch)]
+                            #[patch_attr(derive(Debug, PartialEq))]
+                            $($PUB)* MyStructBuilder -> MyStruct {
+                                $($PUB)* field_i32: i32 = 123,
+                                $($PUB)* field_str: &'static str = "abc",
+                            }, assertions: {
+                                assert!(field_i32 > 0);
+                            }
+                        }
+
+                        let mut my_struct = MyStructBuilder::new().build();
+                        let other = MyStructBuilder::new().field_str("def").build();
+                        let patch = my_struct.diff(&other);
+
+                        assert_eq!(
+                            patch,
+                            MyStructPatch { field_i32: None, field_str: Some("def") }
+                        );
+                        my_struct.apply(patch);
+                        assert_eq!(my_struct.field_str, "def");
+
+                        my_struct.apply(MyStructPatch { field_i32: Some(-1), field_str: None });
                     }
 
                     #[test]
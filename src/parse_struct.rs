@@ -4,43 +4,1450 @@ macro_rules! parse_struct {
     // The way we determine visibility of the generated builder and struct is based on the pattern
     // in: https://github.com/rust-lang-nursery/lazy-static.rs/blob/v0.2.1/src/lib.rs
 
+    // The macro declared by `field_group!` calls back with the fields of the group, which are
+    // parsed before the remaining fields of the struct, and its assertions, which are merged with
+    // the assertions of the struct.
+    (
+        @field_group { $( $GROUP_FIELD_SPEC:tt )* } [ { $( $GROUP_ASSERTION:expr; )* } ]
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: { $( $FIELD_WIP:tt )* },
+        parser_wip: { $( $SPEC_TAIL:tt )* },
+        assertions: { $( $ASSERTION:expr; )* }
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: { $( $FIELD_WIP )* },
+            parser_wip: { $( $GROUP_FIELD_SPEC )* $( $SPEC_TAIL )* },
+            assertions: { $( $ASSERTION; )* $( $GROUP_ASSERTION; )* }
+        }
+    };
+    // At most one of the struct and the group have assertions
+    (
+        @field_group { $( $GROUP_FIELD_SPEC:tt )* } [ $( $GROUP_ASSERTIONS:tt )* ]
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: { $( $FIELD_WIP:tt )* },
+        parser_wip: { $( $SPEC_TAIL:tt )* }
+        $(, assertions: $ASSERTIONS:tt )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: { $( $FIELD_WIP )* },
+            parser_wip: { $( $GROUP_FIELD_SPEC )* $( $SPEC_TAIL )* }
+            $(, assertions: $ASSERTIONS )*
+            $(, assertions: $GROUP_ASSERTIONS )*
+        }
+    };
+
+    // Initialize the struct level options with their default values
+    (
+        purpose: $PURPOSE:ident,
+        meta: [],
+        spec: $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [],
+            options: {
+                default: false,
+                builder_meta: [],
+                constructor: new,
+                build: build,
+                setter_prefix: [],
+                setter_suffix: [],
+                field_groups: [],
+                sources: [],
+                patch: false,
+                patch_meta: [],
+                impl_default: false,
+                validate: false
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // `#[builder(...)]` items on the struct are options for how the struct and builder are
+    // generated, so they are not copied onto the struct
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(default)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: true,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // The names of the generated constructor, build method and setters may be customized.
+    // Setter names are `setter_prefix` + field name + `setter_suffix`.
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(constructor = $NEXT_CONSTRUCTOR:ident)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $NEXT_CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(build = $NEXT_BUILD:ident)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $NEXT_BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(setter_prefix = $NEXT_SETTER_PREFIX:ident)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $NEXT_SETTER_PREFIX ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(setter_suffix = $NEXT_SETTER_SUFFIX:ident)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $NEXT_SETTER_SUFFIX ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Builders with provenance store the source of each field that is set, in a builder field that
+    // is named so that it does not clash with the fields of the struct
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(provenance)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ __sources ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Structs with a patch have a companion struct with an optional value for each field
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(patch)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: true,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Structs without mandatory fields may implement `Default`, using the builder
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(impl_default)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: true,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Structs with consuming builders only have `validate()` when they opt in, as it needs clones
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(validate)] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: true
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Field groups restrict which combinations of their fields may be set, and are checked when
+    // building the struct
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(exactly_one( $( $GROUP_FIELD:ident ),+ ))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: exactly_one, fields: [ $( $GROUP_FIELD ),+ ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(at_most_one( $( $GROUP_FIELD:ident ),+ ))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: at_most_one, fields: [ $( $GROUP_FIELD ),+ ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(at_least_one( $( $GROUP_FIELD:ident ),+ ))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: at_least_one, fields: [ $( $GROUP_FIELD ),+ ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(all_or_none( $( $GROUP_FIELD:ident ),+ ))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: all_or_none, fields: [ $( $GROUP_FIELD ),+ ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // Conditional requirements make a field required when another field is true or is set
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(required_if($FIELD:ident, $CONDITION:ident))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: required_if, fields: [ $FIELD, $CONDITION ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder(required_with($FIELD:ident, $CONDITION:ident))] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [
+                    $( $FIELD_GROUPS )*
+                    { kind: required_with, fields: [ $FIELD, $CONDITION ] }
+                ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // `#[builder_attr(...)]` items are copied onto the builder instead of the struct
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[builder_attr( $( $NEXT_BUILDER_META:meta ),+ )] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* $( #[$NEXT_BUILDER_META] )+ ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // `#[patch_attr(...)]` items are copied onto the patch struct instead of the struct
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
+        spec: #[patch_attr( $( $NEXT_PATCH_META:meta ),+ )] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* ],
+            options: {
+                default: $DEFAULT,
+                builder_meta: [ $( #[$BUILDER_META] )* ],
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ],
+                field_groups: [ $( $FIELD_GROUPS )* ],
+                sources: [ $( $SOURCES )* ],
+                patch: $PATCH,
+                patch_meta: [ $( #[$PATCH_META] )* $( #[$NEXT_PATCH_META] )+ ],
+                impl_default: $IMPL_DEFAULT,
+                validate: $VALIDATE
+            },
+            spec: $( $SPEC )+
+        }
+    };
+
     // Loop through each meta item in SPEC, extract it and prepend it to ITEM_META
     (
         purpose: $PURPOSE:ident,
         meta: [ $( #[$ITEM_META:meta] )* ],
-        spec: #[$NEXT_META:meta] $( $SPEC:tt )+
+        options: { $( $OPTIONS:tt )* },
+        spec: #[$NEXT_META:meta] $( $SPEC:tt )+
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            meta: [ $( #[$ITEM_META] )* #[$NEXT_META] ],
+            options: { $( $OPTIONS )* },
+            spec: $( $SPEC )+
+        }
+    };
+
+    // When we reach here, we have parsed all of the meta items for the struct.
+    // Next we have to extract the tokens for each field into a block, then parse the meta items for
+    // each field. We have to do this because the rust compiler does not allow us to use a macro
+    // within the struct body:
+    //
+    // struct Something {
+    //     parse_fields!( $( $FIELD_SPEC )* ); // compilation failure
+    // }
+    //
+    // It also does not allow us to call a macro as part of another macro:
+    //
+    // // fails because it doesn't attempt to evaluate parse_struct!
+    // a_macro!($something, parse_struct!($another_thing));
+    //
+
+    // When the builder name is omitted, it is derived from the struct name by appending `Builder`.
+    // Identifiers cannot be concatenated by `macro_rules!`, so we use `paste!` to do this.
+    //
+    // These must be matched before the rules below, otherwise `pub` would be matched as the builder
+    // name.
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: pub $MODE:tt $STRUCT:ident {
+            $( $FIELD_SPEC:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        $crate::paste::paste! {
+            parse_struct! {
+                purpose: $PURPOSE,
+                meta: [ $( #[$ITEM_META] )* ],
+                options: { $( $OPTIONS )* },
+                spec: pub [<$STRUCT Builder>] $MODE $STRUCT {
+                    $( $FIELD_SPEC )*
+                }
+                $(, assertions: { $( $ASSERTION; )* } )*
+            }
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $MODE:tt $STRUCT:ident {
+            $( $FIELD_SPEC:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        $crate::paste::paste! {
+            parse_struct! {
+                purpose: $PURPOSE,
+                meta: [ $( #[$ITEM_META] )* ],
+                options: { $( $OPTIONS )* },
+                spec: [<$STRUCT Builder>] $MODE $STRUCT {
+                    $( $FIELD_SPEC )*
+                }
+                $(, assertions: { $( $ASSERTION; )* } )*
+            }
+        }
+    };
+
+    // This macro adds additional blocks to make parsing easier
+    // We match on 'pub' in case the struct and builder should be public
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: pub $BUILDER:ident $MODE:tt $STRUCT:ident {
+            $( $FIELD_SPEC:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ pub ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: {},
+            field_wip: {
+                vis: [],
+                lazy: false,
+                required: false,
+                meta: [],
+                struct_meta: [],
+                builder_meta: [],
+                docs: [],
+                setter: [],
+                no_setter: false,
+                getter: none,
+                nested: [],
+                set_once: false
+            },
+            parser_wip: { $( $FIELD_SPEC )* }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // We must have the private scope match happen after the rule for pub scope.
+    // This is because if we have it the other way around, the following happens:
+    //
+    // * $BUILDER:ident matches `pub`
+    // * $MODE:tt matches the builder name
+    // * $STRUCT:ident attempts to match the -> or => arrow and fails
+    (
+        purpose: $PURPOSE:ident,
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident {
+            $( $FIELD_SPEC:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: {},
+            field_wip: {
+                vis: [],
+                lazy: false,
+                required: false,
+                meta: [],
+                struct_meta: [],
+                builder_meta: [],
+                docs: [],
+                setter: [],
+                no_setter: false,
+                getter: none,
+                nested: [],
+                set_once: false
+            },
+            parser_wip: { $( $FIELD_SPEC )* }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+
+    // `..group_name,` includes the fields of a group declared with `field_group!`
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: { $( $FIELD_WIP:tt )* },
+        parser_wip: {
+            .. $GROUP:ident, $( $SPEC_TAIL:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        $GROUP! {
+            parse_struct! {
+                purpose: $PURPOSE,
+                vis: [ $( $VIS )* ],
+                meta: [ $( #[$ITEM_META] )* ],
+                options: { $( $OPTIONS )* },
+                spec: $BUILDER $MODE $STRUCT,
+                fields: { $( $FIELDS )* },
+                field_wip: { $( $FIELD_WIP )* },
+                parser_wip: { $( $SPEC_TAIL )* }
+                $(, assertions: { $( $ASSERTION; )* } )*
+            }
+        }
+    };
+
+    // Now we have to attempt to wrap each field inside braces {}
+    // This macro looks for meta tokens and extracts them into field_wip
+    //
+    // The fields that have already been parsed are passed through untouched, so we do not need to
+    // match on their contents here.
+    //
+    // `#[builder(...)]` attributes are not real attributes, so they must be matched before other
+    // meta items and are not copied onto the generated fields.
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(lazy)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: true,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(required)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: true,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Custom setter name for this field
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(setter = $SETTER:ident)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $SETTER ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Fields without a setter can only be set through the constructor or their default value
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(no_setter)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: true,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Getters on the struct return a reference to the field by default
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(getter)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: ref,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Getters on the struct may instead return a copy of the field, or a `&str` for `String`s
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(getter = $GETTER:ident)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Nested fields store the builder of the field's struct, which is built with the outer struct
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(nested = $NESTED:ident)] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
     =>
     {
         parse_struct! {
             purpose: $PURPOSE,
-            meta: [ $( #[$ITEM_META] )* #[$NEXT_META] ],
-            spec: $( $SPEC )+
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $NESTED ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-
-    // When we reach here, we have parsed all of the meta items for the struct.
-    // Next we have to extract the tokens for each field into a block, then parse the meta items for
-    // each field. We have to do this because the rust compiler does not allow us to use a macro
-    // within the struct body:
-    //
-    // struct Something {
-    //     parse_fields!( $( $FIELD_SPEC )* ); // compilation failure
-    // }
-    //
-    // It also does not allow us to call a macro as part of another macro:
-    //
-    // // fails because it doesn't attempt to evaluate parse_struct!
-    // a_macro!($something, parse_struct!($another_thing));
-    //
-
-    // This macro adds additional blocks to make parsing easier
-    // We match on 'pub' in case the struct and builder should be public
+    // Set-once fields record when their setter is called again, which fails the build
     (
         purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
-        spec: pub $BUILDER:ident $MODE:tt $STRUCT:ident {
-            $( $FIELD_SPEC:tt )*
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[builder(set_once)] $( $SPEC_TAIL:tt )+
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
@@ -48,26 +1455,56 @@ macro_rules! parse_struct {
     {
         parse_struct! {
             purpose: $PURPOSE,
-            vis: [ pub ],
+            vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
-            fields: {},
-            field_wip: { meta: [] },
-            parser_wip: { $( $FIELD_SPEC )* }
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: true
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-    // We must have the private scope match happen after the rule for pub scope.
-    // This is because if we have it the other way around, the following happens:
-    //
-    // * $BUILDER:ident matches `pub`
-    // * $MODE:tt matches the builder name
-    // * $STRUCT:ident attempts to match the -> or => arrow and fails
+    // `#[struct_attr(...)]` items are only copied onto the struct field, and `#[builder_attr(...)]`
+    // items are only copied onto the builder field. Other meta items are copied onto both.
     (
         purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
-        spec: $BUILDER:ident $MODE:tt $STRUCT:ident {
-            $( $FIELD_SPEC:tt )*
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[struct_attr( $( $NEXT_META:meta ),+ )] $( $SPEC_TAIL:tt )+
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
@@ -75,39 +1512,111 @@ macro_rules! parse_struct {
     {
         parse_struct! {
             purpose: $PURPOSE,
-            vis: [],
+            vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
-            fields: {},
-            field_wip: { meta: [] },
-            parser_wip: { $( $FIELD_SPEC )* }
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* $( #[$NEXT_META] )+ ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-
-    // Now we have to attempt to wrap each field inside braces {}
-    // This macro looks for meta tokens and extracts them into field_wip
     (
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
+        parser_wip: {
+            #[builder_attr( $( $NEXT_META:meta ),+ )] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* $( #[$NEXT_META] )+ ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    // Field docs are also recorded separately, so that they can be copied onto the setter and
+    // constructor
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
         field_wip: {
-            meta: [ $( #[$FIELD_WIP_META:meta] )* ]
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
         parser_wip: {
-            #[$FIELD_WIP_NEXT_META:meta] $( $SPEC_TAIL:tt )+
+            #[doc = $FIELD_WIP_NEXT_DOC:expr] $( $SPEC_TAIL:tt )+
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
@@ -117,20 +1626,77 @@ macro_rules! parse_struct {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
-            fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* #[doc = $FIELD_WIP_NEXT_DOC] ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* #[doc = $FIELD_WIP_NEXT_DOC] ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
             },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            #[$FIELD_WIP_NEXT_META:meta] $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
             field_wip: {
-                meta: [ $( #[$FIELD_WIP_META] )* #[$FIELD_WIP_NEXT_META] ]
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* #[$FIELD_WIP_NEXT_META] ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
             },
             parser_wip: {
                 $( $SPEC_TAIL )+
@@ -139,27 +1705,88 @@ macro_rules! parse_struct {
         }
     };
 
-    // When we reach here, the meta tokens for field_wip should have all been parsed
-    // Therefore we should be able to match on the [pub] field_name: Type = Some(default), pattern
-    // Mandatory field
+    // Public field, we record the visibility and continue parsing the rest of the field
     (
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
+        parser_wip: {
+            pub $( $SPEC_TAIL:tt )+
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        parse_struct! {
+            purpose: $PURPOSE,
+            vis: [ $( $VIS )* ],
+            meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
+            spec: $BUILDER $MODE $STRUCT,
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ pub ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
+            },
+            parser_wip: {
+                $( $SPEC_TAIL )+
+            }
+            $(, assertions: { $( $ASSERTION; )* } )*
+        }
+    };
+
+    // When we reach here, the meta tokens and visibility for field_wip should have all been parsed
+    // Therefore we should be able to match on the field_name: Type = default, pattern
+    //
+    // When `#[builder(default)]` is specified on the struct, fields without a default value use
+    // `Default::default()` unless they are marked with `#[builder(required)]`
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { default: true $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
         field_wip: {
-            meta: [ $( #[$FIELD_WIP_META:meta] )* ]
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: false,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
         parser_wip: {
             $F_NAME:ident: $F_TY:ty,
@@ -173,51 +1800,84 @@ macro_rules! parse_struct {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { default: true $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
-            fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
-                {
-                    req: true,
-                    vis: [],
-                    meta: [ $( #[$FIELD_WIP_META] )* ],
-                    default: None,
-                    spec: $F_NAME: $F_TY
-                },
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: false,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
             },
-            field_wip: { meta: [] },
             parser_wip: {
+                $F_NAME: $F_TY = default,
                 $( $SPEC_TAIL )*
             }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-    // Optional field
+    // Lazy defaults only make sense when there is a default value
     (
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: true,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
+        parser_wip: {
+            $F_NAME:ident: $F_TY:ty,
+            $( $SPEC_TAIL:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
+    {
+        compile_error!(concat!("`#[builder(lazy)]` requires a default value for field: '",
+                               stringify!($F_NAME), "'"));
+    };
+    // Required fields cannot have a default value
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
         field_wip: {
-            meta: [ $( #[$FIELD_WIP_META:meta] )* ]
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: true,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
         parser_wip: {
             $F_NAME:ident: $F_TY:ty = $F_DEFAULT:expr,
@@ -226,59 +1886,109 @@ macro_rules! parse_struct {
         $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
     =>
+    {
+        compile_error!(concat!("`#[builder(required)]` field cannot have a default value: '",
+                               stringify!($F_NAME), "'"));
+    };
+    // Mandatory field
+    (
+        purpose: $PURPOSE:ident,
+        vis: [ $( $VIS:ident )* ],
+        meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
+        spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: false,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
+        },
+        parser_wip: {
+            $F_NAME:ident: $F_TY:ty,
+            $( $SPEC_TAIL:tt )*
+        }
+        $(, assertions: { $( $ASSERTION:expr; )* } )*
+    )
+    =>
     {
         parse_struct! {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
             fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
+                $( $FIELDS )*
                 {
-                    req: false,
-                    vis: [],
+                    req: true,
+                    lazy: false,
+                    vis: [ $( $FIELD_VIS )* ],
                     meta: [ $( #[$FIELD_WIP_META] )* ],
-                    default: $F_DEFAULT,
+                    struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                    builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                    docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                    setter: [ $( $FIELD_WIP_SETTER )* ],
+                    no_setter: $FIELD_NO_SETTER,
+                    getter: $FIELD_GETTER,
+                    nested: [ $( $FIELD_NESTED )* ],
+                    set_once: $FIELD_SET_ONCE,
+                    default: None,
                     spec: $F_NAME: $F_TY
                 },
             },
-            field_wip: { meta: [] },
+            field_wip: {
+                vis: [],
+                lazy: false,
+                required: false,
+                meta: [],
+                struct_meta: [],
+                builder_meta: [],
+                docs: [],
+                setter: [],
+                no_setter: false,
+                getter: none,
+                nested: [],
+                set_once: false
+            },
             parser_wip: {
                 $( $SPEC_TAIL )*
             }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-    // public mandatory field
+    // Optional field using the `Default` implementation of its type
     (
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
-        },
+        fields: { $( $FIELDS:tt )* },
         field_wip: {
-            meta: [ $( #[$FIELD_WIP_META:meta] )* ]
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
         parser_wip: {
-            pub $F_NAME:ident: $F_TY:ty,
+            $F_NAME:ident: $F_TY:ty = default,
             $( $SPEC_TAIL:tt )*
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
@@ -289,54 +1999,54 @@ macro_rules! parse_struct {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
-            fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
-                {
-                    req: true,
-                    vis: [ pub ],
-                    meta: [ $( #[$FIELD_WIP_META] )* ],
-                    default: None,
-                    spec: $F_NAME: $F_TY
-                },
+            fields: { $( $FIELDS )* },
+            field_wip: {
+                vis: [ $( $FIELD_VIS )* ],
+                lazy: $FIELD_LAZY,
+                required: $FIELD_REQUIRED,
+                meta: [ $( #[$FIELD_WIP_META] )* ],
+                struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                setter: [ $( $FIELD_WIP_SETTER )* ],
+                no_setter: $FIELD_NO_SETTER,
+                getter: $FIELD_GETTER,
+                nested: [ $( $FIELD_NESTED )* ],
+                set_once: $FIELD_SET_ONCE
             },
-            field_wip: { meta: [] },
             parser_wip: {
+                $F_NAME: $F_TY = <$F_TY as ::std::default::Default>::default(),
                 $( $SPEC_TAIL )*
             }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
     };
-    // public optional field
+    // Optional field
     (
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: { $( $OPTIONS:tt )* },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
-        },
+        fields: { $( $FIELDS:tt )* },
         field_wip: {
-            meta: [ $( #[$FIELD_WIP_META:meta] )* ]
+            vis: [ $( $FIELD_VIS:ident )* ],
+            lazy: $FIELD_LAZY:ident,
+            required: $FIELD_REQUIRED:ident,
+            meta: [ $( #[$FIELD_WIP_META:meta] )* ],
+            struct_meta: [ $( #[$FIELD_WIP_STRUCT_META:meta] )* ],
+            builder_meta: [ $( #[$FIELD_WIP_BUILDER_META:meta] )* ],
+            docs: [ $( #[doc = $FIELD_WIP_DOC:expr] )* ],
+            setter: [ $( $FIELD_WIP_SETTER:ident )* ],
+            no_setter: $FIELD_NO_SETTER:ident,
+            getter: $FIELD_GETTER:ident,
+            nested: [ $( $FIELD_NESTED:ident )* ],
+            set_once: $FIELD_SET_ONCE:ident
         },
         parser_wip: {
-            pub $F_NAME:ident: $F_TY:ty = $F_DEFAULT:expr,
+            $F_NAME:ident: $F_TY:ty = $F_DEFAULT:expr,
             $( $SPEC_TAIL:tt )*
         }
         $(, assertions: { $( $ASSERTION:expr; )* } )*
@@ -347,26 +2057,41 @@ macro_rules! parse_struct {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            options: { $( $OPTIONS )* },
             spec: $BUILDER $MODE $STRUCT,
             fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
+                $( $FIELDS )*
                 {
                     req: false,
-                    vis: [ pub ],
+                    lazy: $FIELD_LAZY,
+                    vis: [ $( $FIELD_VIS )* ],
                     meta: [ $( #[$FIELD_WIP_META] )* ],
+                    struct_meta: [ $( #[$FIELD_WIP_STRUCT_META] )* ],
+                    builder_meta: [ $( #[$FIELD_WIP_BUILDER_META] )* ],
+                    docs: [ $( #[doc = $FIELD_WIP_DOC] )* ],
+                    setter: [ $( $FIELD_WIP_SETTER )* ],
+                    no_setter: $FIELD_NO_SETTER,
+                    getter: $FIELD_GETTER,
+                    nested: [ $( $FIELD_NESTED )* ],
+                    set_once: $FIELD_SET_ONCE,
                     default: $F_DEFAULT,
                     spec: $F_NAME: $F_TY
                 },
             },
-            field_wip: { meta: [] },
+            field_wip: {
+                vis: [],
+                lazy: false,
+                required: false,
+                meta: [],
+                struct_meta: [],
+                builder_meta: [],
+                docs: [],
+                setter: [],
+                no_setter: false,
+                getter: none,
+                nested: [],
+                set_once: false
+            },
             parser_wip: {
                 $( $SPEC_TAIL )*
             }
@@ -378,19 +2103,36 @@ macro_rules! parse_struct {
         purpose: $PURPOSE:ident,
         vis: [ $( $VIS:ident )* ],
         meta: [ $( #[$ITEM_META:meta] )* ],
+        options: {
+            default: $DEFAULT:ident,
+            builder_meta: [ $( #[$BUILDER_META:meta] )* ],
+            constructor: $CONSTRUCTOR:ident,
+            build: $BUILD:ident,
+            setter_prefix: [ $( $SETTER_PREFIX:tt )* ],
+            setter_suffix: [ $( $SETTER_SUFFIX:tt )* ],
+            field_groups: [ $( $FIELD_GROUPS:tt )* ],
+            sources: [ $( $SOURCES:ident )* ],
+            patch: $PATCH:ident,
+            patch_meta: [ $( #[$PATCH_META:meta] )* ],
+            impl_default: $IMPL_DEFAULT:ident,
+            validate: $VALIDATE:ident
+        },
         spec: $BUILDER:ident $MODE:tt $STRUCT:ident,
-        fields: {
-            $(
-                {
-                    req: $FIELD_REQ:ident,
-                    vis: [ $( $FIELD_VIS:ident )* ],
-                    meta: [ $( #[$FIELD_META:meta] )* ],
-                    default: $FIELD_DEFAULT:expr,
-                    spec: $( $FIELD_SPEC:tt )+
-                },
-            )*
+        fields: { $( $FIELDS:tt )* },
+        field_wip: {
+            vis: [],
+            lazy: false,
+            required: false,
+            meta: [],
+            struct_meta: [],
+            builder_meta: [],
+            docs: [],
+            setter: [],
+            no_setter: false,
+            getter: none,
+            nested: [],
+            set_once: false
         },
-        field_wip: { meta: [] },
         parser_wip: {}
         $(, assertions: { $( $ASSERTION:expr; )* } )*
     )
@@ -400,17 +2142,22 @@ macro_rules! parse_struct {
             purpose: $PURPOSE,
             vis: [ $( $VIS )* ],
             meta: [ $( #[$ITEM_META] )* ],
+            builder_meta: [ $( #[$BUILDER_META] )* ],
+            names: {
+                constructor: $CONSTRUCTOR,
+                build: $BUILD,
+                setter_prefix: [ $( $SETTER_PREFIX )* ],
+                setter_suffix: [ $( $SETTER_SUFFIX )* ]
+            },
+            field_groups: [ $( $FIELD_GROUPS )* ],
+            sources: [ $( $SOURCES )* ],
+            patch: $PATCH,
+            patch_meta: [ $( #[$PATCH_META] )* ],
+            impl_default: $IMPL_DEFAULT,
+            validate: $VALIDATE,
             spec: $BUILDER $MODE $STRUCT,
             fields: {
-                $(
-                    {
-                        req: $FIELD_REQ,
-                        vis: [ $( $FIELD_VIS )* ],
-                        meta: [ $( #[$FIELD_META] )* ],
-                        default: $FIELD_DEFAULT,
-                        spec: $( $FIELD_SPEC )+
-                    },
-                )*
+                $( $FIELDS )*
             }
             $(, assertions: { $( $ASSERTION; )* } )*
         }
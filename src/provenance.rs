@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt;
+use std::panic;
+use std::sync::Once;
+use std::thread;
+
+use field_value::FieldValue;
+
+/// Where the value of a field in a builder with `#[builder(provenance)]` came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// The field uses its declared default value.
+    Default,
+    /// The field has the value of a built struct that was converted back into the builder.
+    Built,
+    /// The field has been set through the constructor or a setter.
+    ///
+    /// This holds the source that was passed to the builder's `set_source()` before the field was
+    /// set, if any.
+    Set(Option<&'static str>),
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Provenance::Default => write!(f, "default"),
+            Provenance::Built => write!(f, "built"),
+            Provenance::Set(None) => write!(f, "set"),
+            Provenance::Set(Some(source)) => write!(f, "set by '{}'", source),
+        }
+    }
+}
+
+/// Lists where the value of each field came from, e.g. `"host: default, port: set"`.
+pub fn describe(fields: &[(&'static str, Provenance)]) -> String {
+    fields
+        .iter()
+        .map(|&(field, provenance)| format!("{}: {}", field, provenance))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+static WRAP_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Whether the panic hook is silenced while building an object struct with provenance.
+    static PANIC_HOOK_SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sources of the fields of a builder with `#[builder(provenance)]`.
+///
+/// This is an implementation detail of the generated builders, and is only public so that the
+/// macros can refer to it.
+#[doc(hidden)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sources {
+    current: Option<&'static str>,
+    fields: Vec<(&'static str, &'static str)>,
+}
+
+impl Sources {
+    /// Sets the source of the fields that are set from now on.
+    pub fn set_current(&mut self, source: &'static str) {
+        self.current = Some(source);
+    }
+
+    /// Records that the field was set from the current source.
+    pub fn record(&mut self, field: &'static str) {
+        let source = self.current;
+        self.record_source(field, source);
+    }
+
+    /// Records that the field was set from the given source.
+    pub fn record_source(&mut self, field: &'static str, source: Option<&'static str>) {
+        self.fields.retain(|&(name, _)| name != field);
+        if let Some(source) = source {
+            self.fields.push((field, source));
+        }
+    }
+
+    /// Returns the source the field was last set from.
+    pub fn source(&self, field: &str) -> Option<&'static str> {
+        self.fields
+            .iter()
+            .find(|&&(name, _)| name == field)
+            .map(|&(_, source)| source)
+    }
+
+    /// Returns where the value of the field came from.
+    pub fn provenance<T>(&self, field: &str, value: &FieldValue<T>) -> Provenance {
+        if value.is_set() {
+            Provenance::Set(self.source(field))
+        } else if value.is_built() {
+            Provenance::Built
+        } else {
+            Provenance::Default
+        }
+    }
+
+    /// Builds an object struct, and panics again with where the value of each field came from if
+    /// building it panics.
+    ///
+    /// The panic hook is silenced on this thread while building, so that a failed build is only
+    /// reported once. To do so, the first build wraps the panic hook that is set at that time.
+    pub fn build_with_provenance<T, F>(build: F, fields: &[(&'static str, Provenance)]) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        // the hook cannot be replaced while the thread is panicking
+        if !thread::panicking() {
+            WRAP_PANIC_HOOK.call_once(|| {
+                let hook = panic::take_hook();
+                panic::set_hook(Box::new(move |info| {
+                    if !PANIC_HOOK_SILENCED.with(Cell::get) {
+                        hook(info);
+                    }
+                }));
+            });
+        }
+
+        let silenced = PANIC_HOOK_SILENCED.with(|silenced| silenced.replace(true));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(build));
+        PANIC_HOOK_SILENCED.with(|silenced_now| silenced_now.set(silenced));
+
+        match result {
+            Ok(value) => value,
+            Err(payload) => Sources::panic_with_provenance(payload, fields),
+        }
+    }
+
+    /// Panics again with the message of a panic from building an object struct, followed by
+    /// where the value of each field came from.
+    ///
+    /// Panics with a payload that is not a message are resumed unchanged.
+    pub fn panic_with_provenance(
+        payload: Box<dyn Any + Send>,
+        fields: &[(&'static str, Provenance)],
+    ) -> ! {
+        let message = match payload.downcast_ref::<String>() {
+            Some(message) => Some(message.clone()),
+            None => payload.downcast_ref::<&'static str>().map(|message| message.to_string()),
+        };
+
+        match message {
+            Some(message) => panic!("{} ({})", message, describe(fields)),
+            None => panic::resume_unwind(payload),
+        }
+    }
+}